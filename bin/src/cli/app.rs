@@ -1,8 +1,10 @@
 use std::path::Path;
 
-use chog::NextVersion;
+use chog::{
+    BumpPolicy, NextVersion, PreReleaseFold, ReleaseDate, ReleaseTitle, TagFormat, VersionScheme,
+};
 
-use super::{App, Error};
+use super::{App, Command, Error};
 
 impl<'a> Default for App<'a> {
     fn default() -> Self {
@@ -15,6 +17,13 @@ impl<'a> Default for App<'a> {
             version: NextVersion::Patch,
             in_file: None,
             out_file: None,
+            date: None,
+            utc: false,
+            scheme: VersionScheme::SemVer,
+            policy: BumpPolicy::Standard,
+            fold: None,
+            tag_format: None,
+            command: Command::Release,
         }
     }
 }
@@ -29,9 +38,14 @@ impl<'a> App<'a> {
         let mut args = args.iter().map(|a| a.as_ref()).peekable();
 
         let mut version = None;
+        let mut preid = None;
+        // `Some(true)` after `yank`, `Some(false)` after `unyank`
+        let mut yank = None;
 
         while let Some(arg) = args.next() {
-            if arg.starts_with("--") {
+            if arg == "--preid" {
+                preid = Some(args.next().ok_or(Error::NoPreid)?);
+            } else if arg.starts_with("--") {
                 if app.handle_long_flag(arg, args.peek())? {
                     // consume next arg
                     args.next();
@@ -41,6 +55,18 @@ impl<'a> App<'a> {
                     // consume next arg
                     args.next();
                 }
+            } else if matches!(app.command, Command::Fix) {
+                return Err(Error::UnexpectedArgument(arg.into()));
+            } else if let Some(yank) = yank {
+                let version = ReleaseTitle::version(arg)?;
+                app.command = match yank {
+                    true => Command::Yank(version),
+                    false => Command::Unyank(version),
+                };
+            } else if version.is_none() && (arg == "yank" || arg == "unyank") {
+                yank = Some(arg == "yank");
+            } else if version.is_none() && arg == "fix" {
+                app.command = Command::Fix;
             } else {
                 version = Some(NextVersion::try_from(arg)?);
             }
@@ -48,11 +74,22 @@ impl<'a> App<'a> {
 
         if app.info || app.help {
             Ok(app)
-        } else if version.is_none() {
-            Err(Error::NoVersion)
-        } else {
-            app.version = version.unwrap();
+        } else if yank.is_some() {
+            match app.command {
+                Command::Release => Err(Error::NoVersion),
+                _ => Ok(app),
+            }
+        } else if matches!(app.command, Command::Fix) {
             Ok(app)
+        } else if let Some(version) = version {
+            app.version = match preid {
+                Some(preid) if version.is_pre_release() => version.with_preid(preid),
+                Some(_) => return Err(Error::PreidWithoutPreRelease),
+                None => version,
+            };
+            Ok(app)
+        } else {
+            Err(Error::NoVersion)
         }
     }
 
@@ -64,6 +101,29 @@ impl<'a> App<'a> {
             "--quiet" => self.quiet = true,
             "--force" => self.force = true,
             "--dry-run" => self.dry_run = true,
+            "--utc" => self.utc = true,
+            "--local" => self.utc = false,
+            "--date" => {
+                self.date = Some(date_from_arg(next)?);
+                used_next = true;
+            }
+            "--scheme" => {
+                self.scheme = scheme_from_arg(next)?;
+                used_next = true;
+            }
+            "--policy" => {
+                self.policy = policy_from_arg(next)?;
+                used_next = true;
+            }
+            "--fold" => {
+                self.fold = fold_from_arg(next)?;
+                used_next = true;
+            }
+            "--tag-format" => {
+                let format = next.ok_or(Error::NoTagFormat)?;
+                self.tag_format = Some(TagFormat::new(format));
+                used_next = true;
+            }
             "--path" => {
                 self.in_file = Some(path_from_arg(next)?);
                 used_next = true;
@@ -108,8 +168,45 @@ fn path_from_arg<'a>(next_arg: Option<&&'a str>) -> Result<&'a Path, Error> {
     }
 }
 
+fn date_from_arg(next_arg: Option<&&str>) -> Result<ReleaseDate, Error> {
+    match next_arg {
+        Some(&arg) => Ok(arg.parse()?),
+        None => Err(Error::NoDate),
+    }
+}
+
+fn scheme_from_arg(next_arg: Option<&&str>) -> Result<VersionScheme, Error> {
+    match next_arg {
+        Some(&arg) => Ok(arg.parse()?),
+        None => Err(Error::NoScheme),
+    }
+}
+
+fn policy_from_arg(next_arg: Option<&&str>) -> Result<BumpPolicy, Error> {
+    match next_arg {
+        Some(arg) if arg.eq_ignore_ascii_case("standard") => Ok(BumpPolicy::Standard),
+        Some(arg) if arg.eq_ignore_ascii_case("cargo") => Ok(BumpPolicy::Cargo),
+        Some(&arg) => Err(Error::InvalidPolicy(arg.into())),
+        None => Err(Error::NoPolicy),
+    }
+}
+
+/// `keep` leaves pre-releases as they are, `merge` folds their changes into
+/// the release and `remove` also removes them.
+fn fold_from_arg(next_arg: Option<&&str>) -> Result<Option<PreReleaseFold>, Error> {
+    match next_arg {
+        Some(arg) if arg.eq_ignore_ascii_case("keep") => Ok(None),
+        Some(arg) if arg.eq_ignore_ascii_case("merge") => Ok(Some(PreReleaseFold::Collapse)),
+        Some(arg) if arg.eq_ignore_ascii_case("remove") => Ok(Some(PreReleaseFold::Remove)),
+        Some(&arg) => Err(Error::InvalidFold(arg.into())),
+        None => Err(Error::NoFold),
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use chog::CalVerFormat;
+
     use super::*;
 
     fn check(expected: Expected, args: &[&str]) {
@@ -143,6 +240,13 @@ mod tests {
             version: NextVersion::Patch,
             in_file: None,
             out_file: None,
+            date: None,
+            utc: false,
+            scheme: VersionScheme::SemVer,
+            policy: BumpPolicy::Standard,
+            fold: None,
+            tag_format: None,
+            command: Command::Release,
         };
         assert_eq!(expected, actual);
     }
@@ -167,6 +271,11 @@ mod tests {
         check(Expected::new().major(), &["major"]);
     }
 
+    #[test]
+    fn auto_version() {
+        check(Expected::new().auto(), &["auto"]);
+    }
+
     #[test]
     fn short_info_flag() {
         check(Expected::new().info(), &["-i"]);
@@ -270,6 +379,163 @@ mod tests {
         check(Expected::new().force().quiet().dry_run().info(), &["-fqid"]);
     }
 
+    #[test]
+    fn date_flag() {
+        check(
+            Expected::new().date("2022-10-20"),
+            &["--date", "2022-10-20", "patch"],
+        );
+    }
+
+    #[test]
+    fn utc_flags() {
+        check(Expected::new().utc(), &["--utc", "patch"]);
+        check(Expected::new(), &["--local", "patch"]);
+        check(Expected::new(), &["--utc", "--local", "patch"]);
+    }
+
+    #[test]
+    fn date_error_no_date() {
+        check_error(Error::NoDate, &["patch", "--date"]);
+    }
+
+    #[test]
+    fn invalid_date_error() {
+        let invalids = ["2022-13-45", "Oct 3", "patch"];
+        for date in invalids {
+            check_error(Error::InvalidDate(date.into()), &["--date", date, "patch"]);
+        }
+    }
+
+    #[test]
+    fn scheme_flag() {
+        let scheme = VersionScheme::CalVer(CalVerFormat::ShortYearMonthMicro);
+        check(
+            Expected::new().scheme(scheme),
+            &["--scheme", "yy.mm.micro", "patch"],
+        );
+        check(
+            Expected::new().minor().scheme(VersionScheme::Numeric),
+            &["minor", "--scheme", "numeric"],
+        )
+    }
+
+    #[test]
+    fn policy_flag() {
+        check(
+            Expected::new().auto().policy(BumpPolicy::Cargo),
+            &["--policy", "cargo", "auto"],
+        );
+        check(Expected::new(), &["--policy", "Standard", "patch"]);
+        check_error(Error::NoPolicy, &["patch", "--policy"]);
+        check_error(
+            Error::InvalidPolicy("semver".into()),
+            &["--policy", "semver", "patch"],
+        );
+    }
+
+    #[test]
+    fn fold_flag() {
+        let cases = [
+            ("keep", None),
+            ("merge", Some(PreReleaseFold::Collapse)),
+            ("Remove", Some(PreReleaseFold::Remove)),
+        ];
+        for (arg, fold) in cases {
+            check(
+                Expected::new().next(NextVersion::Release).fold(fold),
+                &["release", "--fold", arg],
+            );
+        }
+        check_error(Error::NoFold, &["release", "--fold"]);
+        check_error(
+            Error::InvalidFold("collapse".into()),
+            &["--fold", "collapse", "release"],
+        );
+    }
+
+    #[test]
+    fn tag_format_flag() {
+        check(
+            Expected::new().minor().tag_format("chog@{version}"),
+            &["--tag-format", "chog@{version}", "minor"],
+        );
+        check_error(Error::NoTagFormat, &["patch", "--tag-format"]);
+    }
+
+    #[test]
+    fn scheme_errors() {
+        check_error(Error::NoScheme, &["patch", "--scheme"]);
+        check_error(
+            Error::InvalidScheme("calver".into()),
+            &["--scheme", "calver", "patch"],
+        );
+    }
+
+    #[test]
+    fn pre_release_versions() {
+        let cases = [
+            (NextVersion::PreMajor(None), "premajor"),
+            (NextVersion::PreMinor(None), "preminor"),
+            (NextVersion::PrePatch(None), "prepatch"),
+            (NextVersion::PreRelease(None), "prerelease"),
+            (NextVersion::Release, "release"),
+        ];
+        for (version, arg) in cases {
+            check(Expected::new().next(version), &[arg]);
+        }
+    }
+
+    #[test]
+    fn preid_flag() {
+        let expected = || Expected::new().next(NextVersion::PreRelease(Some("rc".into())));
+        check(expected(), &["prerelease", "--preid", "rc"]);
+        check(expected(), &["--preid", "rc", "prerelease"]);
+    }
+
+    #[test]
+    fn preid_errors() {
+        check_error(Error::NoPreid, &["prerelease", "--preid"]);
+        check_error(Error::PreidWithoutPreRelease, &["minor", "--preid", "rc"]);
+    }
+
+    #[test]
+    fn yank_command() {
+        check(Expected::new().yank("1.0.0"), &["yank", "1.0.0"]);
+        check(
+            Expected::new().yank("0.1.0").dry_run(),
+            &["-d", "yank", "v0.1.0"],
+        );
+    }
+
+    #[test]
+    fn unyank_command() {
+        check(Expected::new().unyank("1.0.0"), &["unyank", "1.0.0"]);
+        check(Expected::new().unyank("1.2.3.4"), &["unyank", "1.2.3.4"]);
+    }
+
+    #[test]
+    fn yank_errors() {
+        check_error(Error::NoVersion, &["yank"]);
+        check_error(Error::InvalidVersion("major".into()), &["yank", "major"]);
+        check_error(
+            Error::InvalidVersion("1.2.3.4-rc.1".into()),
+            &["yank", "1.2.3.4-rc.1"],
+        );
+    }
+
+    #[test]
+    fn fix_command() {
+        check(Expected::new().fix(), &["fix"]);
+        check(Expected::new().fix().dry_run(), &["fix", "--dry-run"]);
+    }
+
+    #[test]
+    fn fix_errors() {
+        check_error(Error::UnexpectedArgument("patch".into()), &["fix", "patch"]);
+        check_error(Error::InvalidVersion("fix".into()), &["patch", "fix"]);
+    }
+
     #[test]
     fn short_out_file_error_no_path() {
         check_error(Error::NoVersion, &["-o", "patch"]);
@@ -297,7 +563,7 @@ mod tests {
 
     #[test]
     fn invalid_custom_versions_error() {
-        let invalids = ["1.a.2", "0.0", "0", "2.3.4.5", "2.3.4no"];
+        let invalids = ["1.a.2", "0", "2.3.4no", "1.2.3.4-rc.1"];
         for v in invalids {
             check_error(Error::InvalidVersion(v.into()), &[v]);
         }
@@ -312,6 +578,8 @@ mod tests {
             "1.2.3-beta",
             "v0.0.0",
             "v200.200.200-label",
+            "0.0",
+            "1.2.3.4",
         ];
         for v in valids {
             check(Expected::new().version(v), &[v]);
@@ -347,6 +615,7 @@ mod tests {
         builder_fn! {quiet}
         builder_fn! {force}
         builder_fn! {dry_run}
+        builder_fn! {utc}
 
         fn major(mut self) -> Self {
             self.app.version = NextVersion::Major;
@@ -363,8 +632,58 @@ mod tests {
             self
         }
 
+        fn auto(mut self) -> Self {
+            self.app.version = NextVersion::Auto;
+            self
+        }
+
+        fn next(mut self, version: NextVersion<'a>) -> Self {
+            self.app.version = version;
+            self
+        }
+
         fn version(mut self, v: &'a str) -> Self {
-            self.app.version = NextVersion::Custom(v.try_into().expect("valid custom version"));
+            self.app.version = NextVersion::new(v).expect("valid custom version");
+            self
+        }
+
+        fn date(mut self, date: &str) -> Self {
+            self.app.date = Some(date.parse().expect("valid date"));
+            self
+        }
+
+        fn scheme(mut self, scheme: VersionScheme) -> Self {
+            self.app.scheme = scheme;
+            self
+        }
+
+        fn policy(mut self, policy: BumpPolicy) -> Self {
+            self.app.policy = policy;
+            self
+        }
+
+        fn fold(mut self, fold: Option<PreReleaseFold>) -> Self {
+            self.app.fold = fold;
+            self
+        }
+
+        fn tag_format(mut self, template: &str) -> Self {
+            self.app.tag_format = Some(TagFormat::new(template));
+            self
+        }
+
+        fn yank(mut self, v: &'a str) -> Self {
+            self.app.command = Command::Yank(ReleaseTitle::version(v).expect("valid version"));
+            self
+        }
+
+        fn unyank(mut self, v: &'a str) -> Self {
+            self.app.command = Command::Unyank(ReleaseTitle::version(v).expect("valid version"));
+            self
+        }
+
+        fn fix(mut self) -> Self {
+            self.app.command = Command::Fix;
             self
         }
 
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use chog::InvalidVersion;
+use chog::{InvalidDate, InvalidScheme, InvalidVersion};
 
 use super::Error;
 
@@ -8,9 +8,31 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::UnknownFlag(flag) => write!(f, "unknown flag: `{}`", flag),
+            Self::UnexpectedArgument(arg) => write!(f, "unexpected argument: `{}`", arg),
             Self::InvalidVersion(version) => version.fmt(f),
+            Self::InvalidDate(date) => date.fmt(f),
+            Self::InvalidScheme(scheme) => scheme.fmt(f),
+            Self::InvalidPolicy(policy) => write!(
+                f,
+                "invalid bump policy: `{}`, expected standard or cargo",
+                policy
+            ),
+            Self::InvalidFold(fold) => write!(
+                f,
+                "invalid pre-release fold: `{}`, expected keep, merge or remove",
+                fold
+            ),
             Self::NoVersion => write!(f, "no version provided"),
             Self::NoPath => write!(f, "expected path for given options"),
+            Self::NoDate => write!(f, "expected date for `--date` option"),
+            Self::NoScheme => write!(f, "expected version scheme for `--scheme` option"),
+            Self::NoPolicy => write!(f, "expected bump policy for `--policy` option"),
+            Self::NoFold => write!(f, "expected pre-release fold for `--fold` option"),
+            Self::NoTagFormat => write!(f, "expected tag format for `--tag-format` option"),
+            Self::NoPreid => write!(f, "expected identifier for `--preid` option"),
+            Self::PreidWithoutPreRelease => {
+                write!(f, "`--preid` option needs a pre-release version")
+            }
         }
     }
 }
@@ -20,3 +42,15 @@ impl From<InvalidVersion> for Error {
         Self::InvalidVersion(err)
     }
 }
+
+impl From<InvalidDate> for Error {
+    fn from(err: InvalidDate) -> Self {
+        Self::InvalidDate(err)
+    }
+}
+
+impl From<InvalidScheme> for Error {
+    fn from(err: InvalidScheme) -> Self {
+        Self::InvalidScheme(err)
+    }
+}
@@ -1,6 +1,9 @@
 use std::path::Path;
 
-use chog::{InvalidVersion, NextVersion};
+use chog::{
+    BumpPolicy, InvalidDate, InvalidScheme, InvalidVersion, NextVersion, PreReleaseFold,
+    ReleaseDate, ReleaseTitle, TagFormat, VersionScheme,
+};
 
 mod app;
 mod error;
@@ -15,13 +18,43 @@ pub struct App<'a> {
     pub version: NextVersion<'a>,
     pub in_file: Option<&'a Path>,
     pub out_file: Option<&'a Path>,
+    pub date: Option<ReleaseDate>,
+    pub utc: bool,
+    pub scheme: VersionScheme,
+    pub policy: BumpPolicy,
+    pub fold: Option<PreReleaseFold>,
+    pub tag_format: Option<TagFormat>,
+    pub command: Command<'a>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub enum Command<'a> {
+    /// Release the unreleased changes.
+    Release,
+    Yank(ReleaseTitle<'a>),
+    Unyank(ReleaseTitle<'a>),
+    /// Fix the changelog problems found by the lint rules.
+    Fix,
 }
 
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub enum Error {
     UnknownFlag(String),
+    UnexpectedArgument(String),
     InvalidVersion(InvalidVersion),
+    InvalidDate(InvalidDate),
+    InvalidScheme(InvalidScheme),
+    InvalidPolicy(String),
+    InvalidFold(String),
     NoVersion,
     NoPath,
+    NoDate,
+    NoScheme,
+    NoPolicy,
+    NoFold,
+    NoTagFormat,
+    NoPreid,
+    PreidWithoutPreRelease,
 }
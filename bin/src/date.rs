@@ -0,0 +1,115 @@
+use std::{
+    env, fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use chog::ReleaseDate;
+use time::UtcOffset;
+
+const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+/// A `SOURCE_DATE_EPOCH` value that is not a Unix timestamp of a supported
+/// date.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidEpoch(String);
+
+/// Date from the `SOURCE_DATE_EPOCH` environment variable, in UTC.
+///
+/// See <https://reproducible-builds.org/specs/source-date-epoch/>. An unset
+/// or empty variable is ignored, any other value must be a Unix timestamp.
+pub fn source_date_epoch() -> Result<Option<ReleaseDate>, InvalidEpoch> {
+    match env::var(SOURCE_DATE_EPOCH) {
+        Ok(value) if !value.is_empty() => parse_epoch(&value).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn parse_epoch(value: &str) -> Result<ReleaseDate, InvalidEpoch> {
+    let invalid = || InvalidEpoch(value.into());
+    let secs = value.parse::<i64>().map_err(|_| invalid())?;
+    ReleaseDate::from_timestamp(secs).map_err(|_| invalid())
+}
+
+/// Today's date, in local time unless `utc` is set.
+///
+/// Falls back to UTC where the local offset is unknown.
+pub fn today(utc: bool) -> ReleaseDate {
+    let local_today = || {
+        let offset = UtcOffset::current_local_offset().ok()?;
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        offset_date(secs.try_into().ok()?, offset)
+    };
+    match utc {
+        true => ReleaseDate::today(),
+        false => local_today().unwrap_or_else(ReleaseDate::today),
+    }
+}
+
+/// Date of a Unix timestamp at a UTC offset.
+fn offset_date(secs: i64, offset: UtcOffset) -> Option<ReleaseDate> {
+    let secs = secs.checked_add(offset.whole_seconds().into())?;
+    ReleaseDate::from_timestamp(secs).ok()
+}
+
+impl fmt::Display for InvalidEpoch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: `{}`", SOURCE_DATE_EPOCH, self.0)
+    }
+}
+
+impl std::error::Error for InvalidEpoch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> ReleaseDate {
+        ReleaseDate::new(year, month, day).expect("valid date")
+    }
+
+    #[test]
+    fn epoch_values() {
+        let cases = [
+            ("0", date(1970, 1, 1)),
+            ("-1", date(1969, 12, 31)),
+            ("1664755200", date(2022, 10, 3)),
+            ("1664841599", date(2022, 10, 3)),
+            ("1664841600", date(2022, 10, 4)),
+            ("1709164800", date(2024, 2, 29)),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(Ok(expected), parse_epoch(value), "value: `{}`", value);
+        }
+    }
+
+    #[test]
+    fn offset_dates() {
+        let offset = |hours| UtcOffset::from_hms(hours, 0, 0).expect("valid offset");
+        let cases = [
+            (1664755200, 0, date(2022, 10, 3)),
+            (1664755200, -1, date(2022, 10, 2)),
+            (1664841599, 1, date(2022, 10, 4)),
+            (1664841599, -23, date(2022, 10, 3)),
+            (1709164800, 14, date(2024, 2, 29)),
+            (1709164800, -12, date(2024, 2, 28)),
+        ];
+        for (secs, hours, expected) in cases {
+            let actual = offset_date(secs, offset(hours));
+            assert_eq!(Some(expected), actual, "{} at {}h", secs, hours);
+        }
+        assert_eq!(None, offset_date(i64::MAX, offset(1)));
+    }
+
+    #[test]
+    fn invalid_epoch_values() {
+        let invalids = ["now", "2022-10-03", "1.5", "99999999999999"];
+        for value in invalids {
+            let expected = Err(InvalidEpoch(value.into()));
+            assert_eq!(expected, parse_epoch(value), "value: `{}`", value);
+        }
+        assert_eq!(
+            "invalid SOURCE_DATE_EPOCH: `now`",
+            InvalidEpoch("now".into()).to_string()
+        );
+    }
+}
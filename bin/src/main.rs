@@ -1,13 +1,29 @@
 use std::process::exit;
 
-use cli::App;
+use cli::{App, Command};
 
 mod cli;
+mod date;
+mod run;
 
 const HELP: &str = r#"chog 0.1.0
 
 USAGE:
     chog [OPTIONS] [VERSION]
+    chog [OPTIONS] yank <VERSION>
+    chog [OPTIONS] unyank <VERSION>
+    chog [OPTIONS] fix
+
+COMMANDS:
+    yank <VERSION>
+        Mark a released version as [YANKED].
+
+    unyank <VERSION>
+        Remove the [YANKED] mark from a released version.
+
+    fix
+        Sort releases, fix section headings, remove empty sections
+        and add missing release links.
 
 VERSIONS:
     major 
@@ -19,6 +35,19 @@ VERSIONS:
     patch
         Increase the patch number - *.*.x
 
+    auto
+        Choose major, minor or patch from the unreleased changes.
+
+    premajor, preminor, prepatch
+        Increase the number and start a pre-release - x.*.*-0
+
+    prerelease
+        Increase the pre-release number - *.*.*-rc.x
+        Released versions start a pre-release of the next patch.
+
+    release
+        Release the current pre-release version - 1.0.0-rc.1 to 1.0.0
+
     [0-9].[0-9].[0-9]*
         Specify your own next version. For example: chog 0.2.3
 
@@ -48,6 +77,40 @@ OPTIONS:
         Direct changed file to STDOUT.
         Using this option disables user confirmation.
 
+    --date <YYYY-MM-DD>
+        Date of the new release.
+        Default: SOURCE_DATE_EPOCH environment variable or today
+
+    --utc, --local
+        Take today's date in UTC or in local time.
+        Default: local time, or UTC where the time zone is unknown
+
+    --preid <identifier>
+        Identifier for pre-release versions.
+        For example: chog preminor --preid beta
+
+    --scheme <scheme>
+        Version scheme: semver, YYYY.MM.DD, YY.MM.MICRO or numeric.
+        Calendar versions are made from the release date.
+        Default: semver
+
+    --policy <policy>
+        How versions before 1.0.0 are bumped: standard or cargo.
+        With cargo, major bumps the minor number and minor bumps the
+        patch number, following Cargo's SemVer compatibility rules.
+        Default: standard
+
+    --fold <keep|merge|remove>
+        What happens to the pre-releases of a new release.
+        merge moves their changes into the release, leaving empty
+        pre-release headings, and remove also removes the headings.
+        Default: keep
+
+    --tag-format <template>
+        Tag names in release links, with a {version} placeholder.
+        For example: chog minor --tag-format chog-cli-v{version}
+        Default: the tags of the existing links
+
 "#;
 
 fn main() {
@@ -63,10 +126,19 @@ fn main() {
             );
             exit(64);
         }
-        Ok(app) => update_version(&app),
+        Ok(app) => {
+            let result = match &app.command {
+                Command::Release => run::update_version(&app),
+                Command::Yank(version) => run::yank(&app, version, true),
+                Command::Unyank(version) => run::yank(&app, version, false),
+                Command::Fix => run::fix(&app),
+            };
+            if let Err(err) = result {
+                eprintln!("Error: {}", err);
+                exit(err.exit_code());
+            }
+        }
     }
 }
 
-fn update_version(_app: &App) {}
-
 fn print_info(_app: &App) {}
@@ -0,0 +1,181 @@
+use std::{
+    fmt, fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use chog::{
+    lint::Rule, Changelog, ParseError, ReleaseDate, ReleaseError, ReleaseOptions, ReleaseTitle,
+    UnknownVersion,
+};
+
+use crate::{
+    cli::App,
+    date::{self, InvalidEpoch},
+};
+
+const DEFAULT_PATH: &str = "CHANGELOG.md";
+
+#[derive(Debug)]
+pub enum Error {
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    Parse(ParseError),
+    Release(ReleaseError),
+    Yank(UnknownVersion),
+    Date(InvalidEpoch),
+    Aborted,
+}
+
+impl Error {
+    /// Exit code following the BSD `sysexits.h` conventions.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Aborted => 1,
+            Self::Parse(_) | Self::Release(_) | Self::Yank(_) => 65,
+            Self::Read(..) => 66,
+            Self::Write(..) => 74,
+            Self::Date(_) => 78,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, err) => write!(f, "could not read `{}`: {}", path.display(), err),
+            Self::Write(path, err) => write!(f, "could not write `{}`: {}", path.display(), err),
+            Self::Parse(err) => write!(f, "invalid changelog: {}", err),
+            Self::Release(err) => err.fmt(f),
+            Self::Yank(err) => err.fmt(f),
+            Self::Date(err) => err.fmt(f),
+            Self::Aborted => write!(f, "aborted"),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<ReleaseError> for Error {
+    fn from(err: ReleaseError) -> Self {
+        Self::Release(err)
+    }
+}
+
+/// Release the unreleased changes as the version chosen in `app`.
+pub fn update_version(app: &App) -> Result<(), Error> {
+    let (in_path, input) = read_changelog(app)?;
+    let mut changelog = Changelog::parse(&input)?;
+
+    let mut options = ReleaseOptions::new().scheme(app.scheme).policy(app.policy);
+    if let Some(format) = &app.tag_format {
+        options = options.tag_format(format.clone());
+    }
+    if let Some(fold) = app.fold {
+        options = options.fold_pre_releases(fold);
+    }
+    let version = changelog
+        .release_with(app.version.clone(), release_date(app)?, &options)?
+        .title_string();
+
+    let change = (
+        format!("Release version {} to", version),
+        format!("Released version {} to", version),
+    );
+    save_changelog(app, in_path, &changelog, change)
+}
+
+/// Mark `version` as yanked, or remove the mark when `yank` is false.
+pub fn yank(app: &App, version: &ReleaseTitle, yank: bool) -> Result<(), Error> {
+    let (in_path, input) = read_changelog(app)?;
+    let mut changelog = Changelog::parse(&input)?;
+
+    let (result, (verb, done)) = match yank {
+        true => (changelog.yank(version), ("Yank", "Yanked")),
+        false => (changelog.unyank(version), ("Unyank", "Unyanked")),
+    };
+    let version = result.map_err(Error::Yank)?.title_string();
+
+    let change = (
+        format!("{} version {} in", verb, version),
+        format!("{} version {} in", done, version),
+    );
+    save_changelog(app, in_path, &changelog, change)
+}
+
+/// Fix the problems of every lint rule that can be fixed.
+pub fn fix(app: &App) -> Result<(), Error> {
+    let (in_path, input) = read_changelog(app)?;
+    let mut changelog = Changelog::parse(&input)?;
+
+    if !changelog.fix(&Rule::ALL) && !app.dry_run {
+        if !app.quiet {
+            println!("Nothing to fix in `{}`", in_path.display());
+        }
+        return Ok(());
+    }
+    save_changelog(app, in_path, &changelog, ("Fix".into(), "Fixed".into()))
+}
+
+fn read_changelog<'a>(app: &App<'a>) -> Result<(&'a Path, String), Error> {
+    let in_path = app.in_file.unwrap_or_else(|| Path::new(DEFAULT_PATH));
+    let input = fs::read_to_string(in_path).map_err(|err| Error::Read(in_path.into(), err))?;
+    Ok((in_path, input))
+}
+
+/// Write the changed `changelog` to where `app` directs it.
+///
+/// The `change` describes what is done to the file, before and after it is
+/// made: "Release version 1.0.0 to" and "Released version 1.0.0 to".
+fn save_changelog(
+    app: &App,
+    in_path: &Path,
+    changelog: &Changelog,
+    (change, done): (String, String),
+) -> Result<(), Error> {
+    if app.dry_run {
+        print!("{}", changelog);
+        return Ok(());
+    }
+
+    let out_path = app.out_file.unwrap_or(in_path);
+    if !app.force && app.out_file.is_none() {
+        let question = format!("{} `{}`?", change, out_path.display());
+        if !confirm(&question) {
+            return Err(Error::Aborted);
+        }
+    }
+
+    fs::write(out_path, changelog.to_string()).map_err(|err| Error::Write(out_path.into(), err))?;
+    if !app.quiet {
+        println!("{} `{}`", done, out_path.display());
+    }
+    Ok(())
+}
+
+/// The `--date` option, then `SOURCE_DATE_EPOCH`, then today in local time
+/// or UTC.
+fn release_date(app: &App) -> Result<ReleaseDate, Error> {
+    if let Some(date) = app.date {
+        return Ok(date);
+    }
+    match date::source_date_epoch().map_err(Error::Date)? {
+        Some(date) => Ok(date),
+        None => Ok(date::today(app.utc)),
+    }
+}
+
+/// Ask a yes or no question on STDIN, defaulting to no.
+fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    let _ = io::stdout().flush();
+    let mut answer = String::new();
+    match io::stdin().lock().read_line(&mut answer) {
+        Ok(_) => matches!(answer.trim(), "y" | "Y" | "yes" | "Yes"),
+        Err(_) => false,
+    }
+}
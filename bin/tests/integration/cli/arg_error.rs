@@ -28,6 +28,15 @@ fn invalid_versions() {
     }
 }
 
+#[test]
+fn version_numbers_too_large() {
+    let version = "1.99999999999999999999.0";
+    check_stderr(
+        &format!("invalid version: `{}`, number too large", version),
+        &[version],
+    );
+}
+
 #[test]
 fn no_versions() {
     let invalid_args = [
@@ -1,3 +1,4 @@
 mod arg_error;
 mod help;
+mod release;
 mod util;
@@ -0,0 +1,373 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::Output,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use chog::Changelog;
+
+use super::util::{assert_stderr, assert_stdout, test_program, test_program_with_env};
+
+const FULL: &str = "../test_changelogs/full.md";
+const EMPTY_UNRELEASED: &str = "../test_changelogs/empty_unreleased.md";
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("chog-{}-{}.md", std::process::id(), name))
+}
+
+fn check_released(output: &str, version: &str) {
+    let changelog = Changelog::parse(output).expect("valid changelog output");
+    let release = &changelog.releases()[0];
+    assert_eq!(version, release.title_string());
+    assert_eq!(
+        Some("### Added\n\n- `Config` pretty printing.\n\n### Documentation\n\n- Fix typos in readme.\n- Add example of `Config` pretty printing."),
+        release.content()
+    );
+    assert_eq!(None, changelog.unreleased().content());
+}
+
+#[test]
+fn dry_run_prints_released_changelog() {
+    let output = test_program(&["-d", "-p", FULL, "minor"], "");
+    assert_stderr("", &output);
+    check_released(&String::from_utf8_lossy(&output.stdout), "1.1.0");
+}
+
+#[test]
+fn dry_run_auto_version() {
+    let output = test_program(&["--dry-run", "--path", FULL, "auto"], "");
+    assert_stderr("", &output);
+    check_released(&String::from_utf8_lossy(&output.stdout), "1.1.0");
+}
+
+#[test]
+fn output_file_written() {
+    let out = temp_path("output_file_written");
+    let out_arg = out.to_string_lossy().to_string();
+    let output = test_program(&["-p", FULL, "-o", &out_arg, "2.0.0"], "");
+    assert_stderr("", &output);
+    assert_stdout(
+        &format!("Released version 2.0.0 to `{}`\n", out_arg),
+        &output,
+    );
+
+    let written = fs::read_to_string(&out).expect("output file");
+    fs::remove_file(&out).expect("remove output file");
+    check_released(&written, "2.0.0");
+}
+
+#[test]
+fn in_place_needs_confirmation() {
+    let path = temp_path("in_place_needs_confirmation");
+    let path_arg = path.to_string_lossy().to_string();
+    let original = fs::read_to_string(FULL).expect("test changelog");
+    fs::write(&path, &original).expect("write temp changelog");
+
+    let output = test_program(&["-p", &path_arg, "patch"], "n\n");
+    assert_stderr("Error: aborted\n", &output);
+    assert_eq!(original, fs::read_to_string(&path).expect("temp changelog"));
+
+    let output = test_program(&["-qp", &path_arg, "patch"], "y\n");
+    assert_stderr("", &output);
+    assert_stdout(
+        &format!("Release version 1.0.1 to `{}`? [y/N] ", path_arg),
+        &output,
+    );
+
+    let written = fs::read_to_string(&path).expect("temp changelog");
+    fs::remove_file(&path).expect("remove temp changelog");
+    check_released(&written, "1.0.1");
+}
+
+#[test]
+fn no_unreleased_changes_error() {
+    let output = test_program(&["-d", "-p", EMPTY_UNRELEASED, "patch"], "");
+    assert_stderr("Error: no unreleased changes to release\n", &output);
+    assert_eq!(Some(65), output.status.code());
+}
+
+#[test]
+fn missing_file_error() {
+    let output = test_program(&["-d", "-p", "missing.md", "patch"], "");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("Error: could not read `missing.md`: "),
+        "{}",
+        stderr
+    );
+    assert_eq!(Some(66), output.status.code());
+}
+
+fn released_date(output: &Output) -> Option<String> {
+    let output = String::from_utf8_lossy(&output.stdout);
+    let changelog = Changelog::parse(&output).expect("valid changelog output");
+    changelog.releases()[0].date().map(str::to_owned)
+}
+
+#[test]
+fn date_option_overrides_source_date_epoch() {
+    let env = [("SOURCE_DATE_EPOCH", "1664820000")];
+    let args = ["-d", "-p", FULL, "--date", "2022-10-20", "patch"];
+    let output = test_program_with_env(&args, &env, "");
+    assert_stderr("", &output);
+    assert_eq!(Some("2022-10-20".into()), released_date(&output));
+}
+
+#[test]
+fn date_from_source_date_epoch() {
+    let env = [("SOURCE_DATE_EPOCH", "1664820000")];
+    let output = test_program_with_env(&["-d", "-p", FULL, "patch"], &env, "");
+    assert_stderr("", &output);
+    assert_eq!(Some("2022-10-03".into()), released_date(&output));
+}
+
+#[test]
+fn invalid_source_date_epoch_errors() {
+    let env = [("SOURCE_DATE_EPOCH", "yesterday")];
+    let output = test_program_with_env(&["-d", "-p", FULL, "patch"], &env, "");
+    assert_stderr("Error: invalid SOURCE_DATE_EPOCH: `yesterday`\n", &output);
+    assert_eq!(Some(78), output.status.code());
+}
+
+#[test]
+fn date_defaults_to_today() {
+    let env = [("SOURCE_DATE_EPOCH", "")];
+    let output = test_program_with_env(&["-d", "-p", FULL, "patch"], &env, "");
+    assert_stderr("", &output);
+    let today = chog::ReleaseDate::today().to_string();
+    assert_eq!(Some(today), released_date(&output));
+}
+
+#[test]
+fn utc_flag_takes_today_in_utc() {
+    let env = [("SOURCE_DATE_EPOCH", ""), ("TZ", "UTC-14")];
+    let output = test_program_with_env(&["-d", "--utc", "-p", FULL, "patch"], &env, "");
+    assert_stderr("", &output);
+    let today = chog::ReleaseDate::today().to_string();
+    assert_eq!(Some(today), released_date(&output));
+}
+
+#[test]
+fn local_flag_takes_today_in_local_time() {
+    // POSIX offsets are inverted: `UTC-14` is 14 hours ahead of UTC
+    for (tz, hours) in [("UTC-14", 14), ("UTC+12", -12)] {
+        let env = [("SOURCE_DATE_EPOCH", ""), ("TZ", tz)];
+        let output = test_program_with_env(&["-d", "--local", "-p", FULL, "patch"], &env, "");
+        assert_stderr("", &output);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("time");
+        let secs = i64::try_from(now.as_secs()).expect("timestamp") + hours * 3600;
+        let today = chog::ReleaseDate::from_timestamp(secs).expect("date");
+        assert_eq!(Some(today.to_string()), released_date(&output), "TZ={}", tz);
+    }
+}
+
+#[test]
+fn yank_and_unyank_version() {
+    let out = temp_path("yank_and_unyank_version");
+    let out_arg = out.to_string_lossy().to_string();
+    let output = test_program(&["-p", FULL, "-o", &out_arg, "yank", "0.1.1"], "");
+    assert_stderr("", &output);
+    assert_stdout(&format!("Yanked version 0.1.1 in `{}`\n", out_arg), &output);
+    let yanked = fs::read_to_string(&out).expect("output file");
+    let original = fs::read_to_string(FULL).expect("test changelog");
+    assert_eq!(
+        original.replace(
+            "## [0.1.1] - 2022-09-10",
+            "## [0.1.1] - 2022-09-10 [YANKED]"
+        ),
+        yanked
+    );
+
+    let output = test_program(&["-qfp", &out_arg, "unyank", "0.1.1"], "");
+    assert_stderr("", &output);
+    assert_stdout("", &output);
+    let unyanked = fs::read_to_string(&out).expect("output file");
+    fs::remove_file(&out).expect("remove output file");
+    assert_eq!(original, unyanked);
+}
+
+#[test]
+fn dry_run_yank_numeric_version() {
+    let path = temp_path("dry_run_yank_numeric_version");
+    let path_arg = path.to_string_lossy().to_string();
+    let numeric = fs::read_to_string(FULL)
+        .expect("test changelog")
+        .replace("[1.0.0]", "[1.2.3.4]");
+    fs::write(&path, &numeric).expect("write temp changelog");
+
+    let output = test_program(&["-d", "-p", &path_arg, "yank", "1.2.3.4"], "");
+    fs::remove_file(&path).expect("remove temp changelog");
+    assert_stderr("", &output);
+    assert_stdout(
+        &numeric.replace(
+            "## [1.2.3.4] - 2022-10-03",
+            "## [1.2.3.4] - 2022-10-03 [YANKED]",
+        ),
+        &output,
+    );
+}
+
+#[test]
+fn yank_unknown_version_errors() {
+    let output = test_program(&["-d", "-p", FULL, "yank", "3.0.0"], "");
+    assert_stderr("Error: version `3.0.0` not found\n", &output);
+    assert_eq!(Some(65), output.status.code());
+}
+
+#[test]
+fn dry_run_auto_version_cargo_policy() {
+    let path = temp_path("dry_run_auto_version_cargo_policy");
+    let path_arg = path.to_string_lossy().to_string();
+    let input = "# Changelog\n\n## [Unreleased]\n\n### Changed\n\n- [**BREAKING**] Rename `Config`.\n\n## [0.2.0] - 2022-09-20\n\n### Added\n\n- `Config` type.\n";
+    fs::write(&path, input).expect("write temp changelog");
+
+    let cargo = test_program(&["-d", "-p", &path_arg, "--policy", "cargo", "auto"], "");
+    let standard = test_program(&["-d", "-p", &path_arg, "auto"], "");
+    fs::remove_file(&path).expect("remove temp changelog");
+    for (expected, output) in [("0.3.0", cargo), ("1.0.0", standard)] {
+        assert_stderr("", &output);
+        let output = String::from_utf8_lossy(&output.stdout);
+        let changelog = Changelog::parse(&output).expect("valid changelog output");
+        assert_eq!(expected, changelog.releases()[0].title_string());
+    }
+}
+
+#[test]
+fn dry_run_release_fold() {
+    let path = temp_path("dry_run_release_fold");
+    let path_arg = path.to_string_lossy().to_string();
+    let input = "# Changelog\n\n## [Unreleased]\n\n### Fixed\n\n- Crash.\n\n## [1.0.0-rc.1] - 2022-10-01\n\n### Added\n\n- `Config` type.\n\n## [0.2.0] - 2022-09-20\n\n### Added\n\n- `App` type.\n";
+    fs::write(&path, input).expect("write temp changelog");
+
+    let run = |fold| test_program(&["-d", "-p", &path_arg, "--fold", fold, "release"], "");
+    let outputs = [run("keep"), run("merge"), run("remove")];
+    fs::remove_file(&path).expect("remove temp changelog");
+
+    let folded = "### Added\n\n- `Config` type.\n\n### Fixed\n\n- Crash.";
+    let expected: [&[_]; 3] = [
+        &[
+            ("1.0.0", Some("### Fixed\n\n- Crash.")),
+            ("1.0.0-rc.1", Some("### Added\n\n- `Config` type.")),
+        ],
+        &[("1.0.0", Some(folded)), ("1.0.0-rc.1", None)],
+        &[
+            ("1.0.0", Some(folded)),
+            ("0.2.0", Some("### Added\n\n- `App` type.")),
+        ],
+    ];
+    for (expected, output) in expected.into_iter().zip(outputs) {
+        assert_stderr("", &output);
+        let output = String::from_utf8_lossy(&output.stdout);
+        let changelog = Changelog::parse(&output).expect("valid changelog output");
+        let actual: Vec<_> = changelog.releases()[..2]
+            .iter()
+            .map(|rel| (rel.title_string(), rel.content()))
+            .collect();
+        let expected: Vec<_> = expected.iter().map(|&(t, c)| (t.to_string(), c)).collect();
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn dry_run_pre_release_version() {
+    let args = ["-d", "-p", FULL, "preminor", "--preid", "beta"];
+    let output = test_program(&args, "");
+    assert_stderr("", &output);
+    check_released(&String::from_utf8_lossy(&output.stdout), "1.1.0-beta.0");
+}
+
+#[test]
+fn dry_run_calver_scheme() {
+    let args = [
+        "-d",
+        "-p",
+        FULL,
+        "--scheme",
+        "YYYY.MM.DD",
+        "--date",
+        "2024-10-03",
+    ];
+    let output = test_program(&[&args[..], &["auto"]].concat(), "");
+    assert_stderr("", &output);
+    check_released(&String::from_utf8_lossy(&output.stdout), "2024.10.3");
+}
+
+#[test]
+fn dry_run_numeric_scheme_custom_version() {
+    let args = ["-d", "-p", FULL, "--scheme", "numeric", "1.2.3.5"];
+    let output = test_program(&args, "");
+    assert_stderr("", &output);
+    check_released(&String::from_utf8_lossy(&output.stdout), "1.2.3.5");
+}
+
+#[test]
+fn numeric_version_in_semver_scheme_errors() {
+    let output = test_program(&["-d", "-p", FULL, "1.2.3.5"], "");
+    assert_stderr(
+        "Error: version `1.2.3.5` is not a semver version\n",
+        &output,
+    );
+    assert_eq!(Some(65), output.status.code());
+}
+
+#[test]
+fn custom_version_not_in_calver_scheme_errors() {
+    let args = ["-d", "-p", FULL, "--scheme", "YY.MM.MICRO", "1.2.3"];
+    let output = test_program(&args, "");
+    assert_stderr(
+        "Error: version `1.2.3` is not a YY.MM.MICRO version\n",
+        &output,
+    );
+    assert_eq!(Some(65), output.status.code());
+}
+
+#[test]
+fn scheme_without_pre_releases_errors() {
+    let output = test_program(&["-d", "-p", FULL, "--scheme", "numeric", "prerelease"], "");
+    assert_stderr(
+        "Error: the numeric version scheme has no pre-releases\n",
+        &output,
+    );
+    assert_eq!(Some(65), output.status.code());
+}
+
+#[test]
+fn dry_run_fix() {
+    let path = temp_path("dry_run_fix");
+    let path_arg = path.to_string_lossy().to_string();
+    let original = fs::read_to_string(FULL).expect("test changelog");
+    let broken = original
+        .replace("### Changed", "### changed")
+        .replace("### Added\n\n- Allow", "### Fixed\n\n### Added\n\n- Allow");
+    fs::write(&path, &broken).expect("write temp changelog");
+
+    let output = test_program(&["fix", "--dry-run", "-p", &path_arg], "");
+    fs::remove_file(&path).expect("remove temp changelog");
+    assert_stderr("", &output);
+    assert_stdout(&original, &output);
+}
+
+#[test]
+fn fix_nothing_to_fix() {
+    let output = test_program(&["-p", FULL, "fix"], "");
+    assert_stderr("", &output);
+    assert_stdout(&format!("Nothing to fix in `{}`\n", FULL), &output);
+}
+
+#[test]
+fn dry_run_tag_format() {
+    let args = ["-d", "-p", FULL, "--tag-format", "chog@{version}", "minor"];
+    let output = test_program(&args, "");
+    assert_stderr("", &output);
+    let output = String::from_utf8_lossy(&output.stdout);
+    let changelog = Changelog::parse(&output).expect("valid changelog output");
+    assert_eq!(
+        Some("https://github.com/user/repo/releases/tag/chog@1.1.0"),
+        changelog.releases()[0].url()
+    );
+    assert_eq!(
+        Some("https://github.com/user/repo/compare/chog@1.1.0...HEAD"),
+        changelog.unreleased().url()
+    );
+}
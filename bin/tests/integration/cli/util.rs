@@ -4,6 +4,12 @@ use std::{
 };
 
 pub fn test_program(args: &[&str], input: &str) -> Output {
+    test_program_with_env(args, &[], input)
+}
+
+/// Run the program with extra environment variables, an empty value removes
+/// the variable.
+pub fn test_program_with_env(args: &[&str], env: &[(&str, &str)], input: &str) -> Output {
     let mut full_args = vec!["run", "-q"];
     let target = std::env::var("RUSTC_TARGET");
     if let Ok(ref target) = target {
@@ -17,6 +23,12 @@ pub fn test_program(args: &[&str], input: &str) -> Output {
     cmd.args(full_args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
+    for &(key, value) in env {
+        match value.is_empty() {
+            true => cmd.env_remove(key),
+            false => cmd.env(key, value),
+        };
+    }
 
     // setup stdin
     cmd.stdin(match input.is_empty() {
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+use crate::{BreakingMarkers, Entry, Release, Section};
+
+impl<'m> Default for BreakingMarkers<'m> {
+    fn default() -> Self {
+        Self {
+            markers: vec![
+                Cow::Borrowed("[**BREAKING CHANGE**]"),
+                Cow::Borrowed("[**BREAKING**]"),
+            ],
+        }
+    }
+}
+
+impl<'m> BreakingMarkers<'m> {
+    /// Markers recognized by default: `[**BREAKING CHANGE**]` and
+    /// `[**BREAKING**]`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recognize only the given markers.
+    pub fn with<I, T>(markers: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'m, str>>,
+    {
+        Self {
+            markers: markers.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Recognize an additional marker.
+    pub fn add_marker<T: Into<Cow<'m, str>>>(mut self, marker: T) -> Self {
+        self.markers.push(marker.into());
+        self
+    }
+
+    pub fn markers(&self) -> impl Iterator<Item = &str> {
+        self.markers.iter().map(|marker| marker.as_ref())
+    }
+
+    /// The text following a leading breaking change marker.
+    ///
+    /// Markers are matched ignoring ASCII case.
+    pub fn strip<'t>(&self, text: &'t str) -> Option<&'t str> {
+        let text = text.trim_start();
+        self.markers
+            .iter()
+            .filter(|marker| !marker.is_empty())
+            .find_map(|marker| {
+                let prefix = text.get(..marker.len())?;
+                match prefix.eq_ignore_ascii_case(marker) {
+                    true => Some(text[marker.len()..].trim_start()),
+                    false => None,
+                }
+            })
+    }
+
+    /// Whether `text` starts with a breaking change marker.
+    pub fn is_marked(&self, text: &str) -> bool {
+        self.strip(text).is_some()
+    }
+}
+
+impl<'c> Entry<'c> {
+    /// Whether the entry starts with one of the default breaking change
+    /// markers.
+    pub fn is_breaking(&self) -> bool {
+        self.is_breaking_with(&BreakingMarkers::default())
+    }
+
+    pub fn is_breaking_with(&self, markers: &BreakingMarkers) -> bool {
+        markers.is_marked(self.text())
+    }
+}
+
+impl<'c> Section<'c> {
+    /// Whether the section heading starts with one of the default breaking
+    /// change markers, such as `### [**BREAKING**] Project changes`.
+    pub fn is_breaking(&self) -> bool {
+        self.is_breaking_with(&BreakingMarkers::default())
+    }
+
+    pub fn is_breaking_with(&self, markers: &BreakingMarkers) -> bool {
+        markers.is_marked(&self.heading)
+    }
+
+    /// Whether the section heading or any of its entries is breaking.
+    pub fn has_breaking_changes_with(&self, markers: &BreakingMarkers) -> bool {
+        match self.is_breaking_with(markers) {
+            true => !self.is_empty(),
+            false => self.all_entries().any(|e| e.is_breaking_with(markers)),
+        }
+    }
+}
+
+impl<'c> Release<'c> {
+    /// Whether the release contains any breaking changes, using the default
+    /// markers.
+    pub fn has_breaking_changes(&self) -> bool {
+        self.has_breaking_changes_with(&BreakingMarkers::default())
+    }
+
+    /// Whether the release contains a breaking entry, or a non-empty section
+    /// with a breaking heading.
+    pub fn has_breaking_changes_with(&self, markers: &BreakingMarkers) -> bool {
+        let changes = self.changes_with(markers);
+        changes
+            .intro()
+            .iter()
+            .any(|entry| entry.is_breaking_with(markers))
+            || changes
+                .sections()
+                .iter()
+                .any(|section| section.has_breaking_changes_with(markers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_markers() {
+        let markers = BreakingMarkers::default();
+        assert_eq!(
+            Some("Remove `Config::new`."),
+            markers.strip("[**BREAKING CHANGE**] Remove `Config::new`.")
+        );
+        assert_eq!(Some("Owned."), markers.strip("[**breaking**] Owned."));
+        assert_eq!(None, markers.strip("Not [**BREAKING**]."));
+        assert_eq!(None, markers.strip("**BREAKING** Other style."));
+    }
+
+    #[test]
+    fn custom_markers() {
+        let markers = BreakingMarkers::with(["BREAKING:"]).add_marker("💥");
+        assert!(markers.is_marked("BREAKING: api"));
+        assert!(markers.is_marked("💥 api"));
+        assert!(!markers.is_marked("[**BREAKING**] api"));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Changelog, Unreleased};
+use crate::{
+    layout::Layout, Changelog, LinkStyle, Release, ReleaseTitle, SemanticVersion, Unreleased,
+    VersionScheme,
+};
 
 impl<'c> Default for Changelog<'c> {
     fn default() -> Self {
@@ -7,6 +10,8 @@ impl<'c> Default for Changelog<'c> {
             unreleased: Unreleased::empty(),
             releases: Vec::new(),
             misc_links: Vec::new(),
+            link_style: LinkStyle::default(),
+            layout: Layout::default(),
         }
     }
 }
@@ -15,4 +20,57 @@ impl<'c> Changelog<'c> {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn header(&self) -> Option<&str> {
+        self.header.as_deref()
+    }
+
+    pub fn unreleased(&self) -> &Release<'c> {
+        &self.unreleased
+    }
+
+    pub fn unreleased_mut(&mut self) -> &mut Release<'c> {
+        &mut self.unreleased
+    }
+
+    pub fn releases(&self) -> &[Release<'c>] {
+        &self.releases
+    }
+
+    pub fn releases_mut(&mut self) -> &mut [Release<'c>] {
+        &mut self.releases
+    }
+
+    /// Highest semantic version of all releases.
+    pub fn latest_version(&self) -> Option<&SemanticVersion<'c>> {
+        self.releases
+            .iter()
+            .filter_map(|rel| rel.title.semver())
+            .max()
+    }
+
+    /// The greatest release title that is a version of the `scheme`.
+    pub fn latest_title(&self, scheme: VersionScheme) -> Option<&ReleaseTitle<'c>> {
+        self.releases
+            .iter()
+            .filter(|rel| scheme.matches(rel))
+            .map(|rel| &rel.title)
+            .max()
+    }
+
+    /// How release urls are written, as found when parsing.
+    pub fn link_style(&self) -> LinkStyle {
+        self.link_style
+    }
+
+    /// Change how release urls are written.
+    ///
+    /// Converts the whole changelog, as the urls are kept with each release.
+    pub fn set_link_style(&mut self, style: LinkStyle) {
+        self.link_style = style;
+    }
+
+    pub fn misc_links(&self) -> impl Iterator<Item = &str> {
+        self.misc_links.iter().map(|link| link.as_ref())
+    }
 }
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use crate::{
     util::{trim_to_optcow_borrow, trim_to_optcow_owned},
-    Changelog, ChangelogBuilder, Release,
+    Changelog, ChangelogBuilder, LinkStyle, Release,
 };
 
 impl<'c> Default for ChangelogBuilder<'c> {
@@ -67,4 +67,9 @@ impl<'c> ChangelogBuilder<'c> {
         self.changelog.misc_links = links.into_iter().map(Cow::Owned).collect();
         self
     }
+
+    pub fn link_style(mut self, style: LinkStyle) -> Self {
+        self.changelog.link_style = style;
+        self
+    }
 }
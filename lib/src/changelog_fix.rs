@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+
+use crate::{
+    links::derive_url, lint::Rule, BreakingMarkers, Changelog, Changes, Release, ReleaseTitle,
+    Section,
+};
+
+impl<'c> Changelog<'c> {
+    /// Fix the problems the lint `rules` report, where it can be done without
+    /// guessing.
+    ///
+    /// - [`Rule::ReleaseOrder`]: sort the releases by their titles, newest
+    ///   first.
+    /// - [`Rule::SectionHeading`]: spell standard section headings like
+    ///   `### Added`, and put the sections in the standard order.
+    /// - [`Rule::EmptySection`]: remove sections without entries.
+    /// - [`Rule::MissingLink`]: add release urls in the style of an existing
+    ///   url.
+    ///
+    /// Other rules are ignored. Returns whether anything changed.
+    pub fn fix(&mut self, rules: &[Rule]) -> bool {
+        let before = self.clone();
+        if rules.contains(&Rule::ReleaseOrder) {
+            self.releases.sort_by(|l, r| r.title.cmp(&l.title));
+        }
+
+        let headings = rules.contains(&Rule::SectionHeading);
+        let empty = rules.contains(&Rule::EmptySection);
+        if headings || empty {
+            for release in std::iter::once(&mut self.unreleased).chain(self.releases.iter_mut()) {
+                fix_sections(release, headings, empty);
+            }
+        }
+
+        if rules.contains(&Rule::MissingLink) {
+            self.add_missing_urls();
+        }
+        *self != before
+    }
+
+    /// Give releases without a url one derived from the url of another
+    /// release.
+    fn add_missing_urls(&mut self) {
+        let versions: Vec<_> = self
+            .releases
+            .iter()
+            .enumerate()
+            .filter(|(_, rel)| {
+                matches!(
+                    rel.title,
+                    ReleaseTitle::SemVer(_) | ReleaseTitle::Numeric(_)
+                )
+            })
+            .map(|(index, rel)| (index, rel.title_string()))
+            .collect();
+        let latest = versions.first().map(|(_, version)| version.clone());
+
+        let example = versions
+            .iter()
+            .find_map(|(index, version)| Some((self.releases[*index].url()?, version.clone())))
+            .or_else(|| Some((self.unreleased.url()?, latest.clone()?)));
+        let Some((example, example_version)) = example.map(|(url, v)| (url.to_string(), v)) else {
+            return;
+        };
+
+        for (i, (index, version)) in versions.iter().enumerate() {
+            if self.releases[*index].url.is_none() {
+                let previous = versions.get(i + 1).map(|(_, version)| version.as_str());
+                let url = derive_url(&example, &example_version, previous, Some(version));
+                self.releases[*index].url = url.map(Cow::Owned);
+            }
+        }
+        if self.unreleased.url.is_none() {
+            let url = derive_url(&example, &example_version, latest.as_deref(), None);
+            self.unreleased.url = url.map(Cow::Owned);
+        }
+    }
+}
+
+/// Fix the section `headings` and remove `empty` sections of the release
+/// content, which is only rewritten when it changes.
+fn fix_sections(release: &mut Release, headings: bool, empty: bool) {
+    let Some(content) = release.content() else {
+        return;
+    };
+    let mut changes = Changes::parse(content);
+    if empty {
+        changes.sections.retain(|section| !section.is_empty());
+    }
+    if headings {
+        changes.sections.iter_mut().for_each(fix_heading);
+        changes.sections.sort_by_key(|section| section.kind.rank());
+    }
+
+    let fixed = changes.to_string();
+    if fixed != content {
+        release.set_content(fixed);
+    }
+}
+
+/// Spell the heading of a standard section like its kind, keeping any
+/// breaking change marker.
+fn fix_heading(section: &mut Section) {
+    if section.kind.is_custom() {
+        return;
+    }
+    let heading = section.heading.as_ref();
+    let name = BreakingMarkers::default()
+        .strip(heading)
+        .unwrap_or(heading)
+        .trim();
+    if let Some(index) = heading
+        .rfind(name)
+        .filter(|_| name != section.kind.as_str())
+    {
+        let fixed = format!(
+            "{}{}{}",
+            &heading[..index],
+            section.kind,
+            &heading[index + name.len()..]
+        );
+        section.heading = Cow::Owned(fixed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed(input: &str, rules: &[Rule]) -> String {
+        let mut changelog = Changelog::parse(input).expect("valid changelog");
+        changelog.fix(rules);
+        changelog.to_string()
+    }
+
+    #[test]
+    fn sort_releases() {
+        let input = "## [Unreleased]\n\n## [1.0.0]\n\n## [1.1.0]\n\n## [0.1.0]\n";
+        let expected = "## [Unreleased]\n\n## [1.1.0]\n\n## [1.0.0]\n\n## [0.1.0]\n";
+        assert_eq!(expected, fixed(input, &[Rule::ReleaseOrder]));
+        assert_eq!(input, fixed(input, &[Rule::EmptySection]));
+    }
+
+    #[test]
+    fn fix_section_headings() {
+        let input = "## [Unreleased]\n\n\
+            ### fixed\n\n- Bug.\n\n\
+            ### Documentation\n\n- Docs.\n\n\
+            ### [**BREAKING**] removed\n\n- Api.\n\n\
+            ### ADDED\n\n- Feature.\n";
+        let expected = "## [Unreleased]\n\n\
+            ### Added\n\n- Feature.\n\n\
+            ### [**BREAKING**] Removed\n\n- Api.\n\n\
+            ### Fixed\n\n- Bug.\n\n\
+            ### Documentation\n\n- Docs.\n";
+        assert_eq!(expected, fixed(input, &[Rule::SectionHeading]));
+    }
+
+    #[test]
+    fn remove_empty_sections() {
+        let input = "## [Unreleased]\n\n### Added\n\n### Fixed\n\n- Bug.\n\n\
+            ## [1.0.0] - 2022-10-03\n\n### Removed\n";
+        let expected = "## [Unreleased]\n\n### Fixed\n\n- Bug.\n\n## [1.0.0] - 2022-10-03\n";
+        assert_eq!(expected, fixed(input, &[Rule::EmptySection]));
+    }
+
+    #[test]
+    fn add_missing_urls() {
+        let input = "## [Unreleased]\n\n## [1.1.0]\n\n## [1.0.0]\n\n## [0.1.0]\n\n\
+            [1.0.0]: https://a.b/compare/v0.1.0...v1.0.0\n";
+        let expected = "## [Unreleased]\n\n## [1.1.0]\n\n## [1.0.0]\n\n## [0.1.0]\n\n\
+            [Unreleased]: https://a.b/compare/v1.1.0...HEAD\n\
+            [1.1.0]: https://a.b/compare/v1.0.0...v1.1.0\n\
+            [1.0.0]: https://a.b/compare/v0.1.0...v1.0.0\n\
+            [0.1.0]: https://a.b/releases/tag/v0.1.0\n";
+        assert_eq!(expected, fixed(input, &[Rule::MissingLink]));
+    }
+
+    #[test]
+    fn nothing_to_fix() {
+        let input = "## [Unreleased]\n\n### Added\n\n- Feature.\n";
+        let mut changelog = Changelog::parse(input).expect("valid changelog");
+        assert!(!changelog.fix(&Rule::ALL));
+        assert_eq!(input, changelog.to_string());
+    }
+}
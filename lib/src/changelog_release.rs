@@ -0,0 +1,469 @@
+use std::borrow::Cow;
+
+use crate::{
+    links::{rebase_unreleased_url, release_urls, repo_release_urls},
+    BumpPolicy, Changelog, NextVersion, PreReleaseFold, Release, ReleaseDate, ReleaseError,
+    ReleaseOptions, ReleaseTitle, RepoLinks, SemanticVersion, TagFormat, VersionScheme,
+};
+
+impl ReleaseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How version keywords are applied, [`BumpPolicy::Standard`] by default.
+    pub fn policy(mut self, policy: BumpPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Leave link urls unchanged.
+    ///
+    /// By default the unreleased url is updated to compare from the new
+    /// release, and the new release gets a url in the style of the previous
+    /// release.
+    pub fn keep_links(mut self, keep: bool) -> Self {
+        self.keep_links = keep;
+        self
+    }
+
+    /// Generate link urls for the repository, instead of following the
+    /// existing links.
+    pub fn repo_links(mut self, links: RepoLinks) -> Self {
+        self.repo_links = Some(links);
+        self
+    }
+
+    /// Tag names used in link urls.
+    ///
+    /// By default new tags follow the tags in the existing links, and
+    /// [`RepoLinks`] urls use `v1.2.3` tags.
+    pub fn tag_format(mut self, format: TagFormat) -> Self {
+        self.tag_format = Some(format);
+        self
+    }
+
+    /// Fold the changes of pre-releases into their release.
+    ///
+    /// When a pre-release such as `1.0.0-rc.1` is promoted to `1.0.0`, the
+    /// entries of all `1.0.0` pre-releases are added to the release, once
+    /// each and grouped by change kind. The pre-releases are then handled as
+    /// `fold` says. By default pre-releases are left as they are.
+    pub fn fold_pre_releases(mut self, fold: PreReleaseFold) -> Self {
+        self.fold_pre_releases = Some(fold);
+        self
+    }
+
+    /// How the next version is made, [`VersionScheme::SemVer`] by default.
+    ///
+    /// The next version follows the latest release of the scheme, other
+    /// release titles are ignored.
+    pub fn scheme(mut self, scheme: VersionScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+}
+
+impl<'c> Changelog<'c> {
+    /// Move the unreleased changes into a new release.
+    ///
+    /// The new version is `next` applied to the latest released version, or
+    /// to `0.0.0` when there are no releases yet. The unreleased section is
+    /// left empty, and its url is updated to compare from the new release.
+    pub fn release(
+        &mut self,
+        next: NextVersion,
+        date: ReleaseDate,
+    ) -> Result<&Release<'c>, ReleaseError> {
+        self.release_with(next, date, &ReleaseOptions::default())
+    }
+
+    pub fn release_with(
+        &mut self,
+        next: NextVersion,
+        date: ReleaseDate,
+        options: &ReleaseOptions,
+    ) -> Result<&Release<'c>, ReleaseError> {
+        if self.unreleased.changes().is_empty() {
+            return Err(ReleaseError::NoChanges);
+        }
+        if let NextVersion::Custom(custom) = &next {
+            self.ensure_new_version(custom)?;
+        }
+
+        let next = self.resolve_next_version(next, options.policy);
+        let previous = self.latest_title(options.scheme).cloned();
+        let version = options.scheme.bump(previous.as_ref(), &next, date)?;
+        self.ensure_new_version(&version)?;
+        let title = version.to_string();
+        let folded = match (options.fold_pre_releases, version.semver()) {
+            (Some(fold), Some(version)) => self.fold_pre_releases(version, fold),
+            _ => false,
+        };
+        // the folded pre-releases are part of the new release, so it compares
+        // from the latest stable release
+        let base = match folded {
+            true => self.latest_stable_title(options.scheme).cloned(),
+            false => previous.clone(),
+        };
+
+        let mut url = None;
+        if !options.keep_links {
+            let previous_url = base.as_ref().and_then(|base| {
+                self.releases
+                    .iter()
+                    .find(|rel| &rel.title == base)
+                    .and_then(Release::url)
+            });
+            let (unreleased_url, release_url) = match (&options.repo_links, &base) {
+                (Some(links), _) => {
+                    let format = options.tag_format.clone().unwrap_or_default();
+                    let base = base.as_ref().map(|version| format.tag(version));
+                    let tag = format.tag(&version);
+                    let (unreleased_url, release_url) =
+                        repo_release_urls(links, previous_url, base.as_deref(), &tag);
+                    (Some(unreleased_url), Some(release_url))
+                }
+                (None, Some(base)) => {
+                    let base = base.to_string();
+                    let rebased = match (folded, self.unreleased.url(), &previous) {
+                        (true, Some(url), Some(previous)) => rebase_unreleased_url(
+                            url,
+                            &previous.to_string(),
+                            &base,
+                            options.tag_format.as_ref(),
+                        ),
+                        _ => None,
+                    };
+                    let (unreleased_url, previous_url) = match &rebased {
+                        Some(url) => (Some(url.as_str()), None),
+                        None => (self.unreleased.url(), previous_url),
+                    };
+                    release_urls(
+                        unreleased_url,
+                        previous_url,
+                        &base,
+                        &title,
+                        options.tag_format.as_ref(),
+                    )
+                }
+                (None, None) => (None, None),
+            };
+            if let Some(unreleased_url) = unreleased_url {
+                self.unreleased.url = Some(Cow::Owned(unreleased_url));
+            }
+            url = release_url.map(Cow::Owned);
+        }
+
+        let release = Release {
+            title: version,
+            url,
+            date: Some(date.into()),
+            content: self.unreleased.content.take(),
+            yanked: false,
+        };
+        self.releases.insert(0, release);
+
+        Ok(&self.releases[0])
+    }
+
+    fn ensure_new_version(&self, version: &ReleaseTitle) -> Result<(), ReleaseError> {
+        match self.releases.iter().any(|rel| &rel.title == version) {
+            true => Err(ReleaseError::VersionExists(version.to_string())),
+            false => Ok(()),
+        }
+    }
+
+    /// The greatest release title of the `scheme` that is not a pre-release.
+    fn latest_stable_title(&self, scheme: VersionScheme) -> Option<&ReleaseTitle<'c>> {
+        self.releases
+            .iter()
+            .filter(|rel| scheme.matches(rel))
+            .map(|rel| &rel.title)
+            .filter(|title| !title.semver().is_some_and(SemanticVersion::is_pre_release))
+            .max()
+    }
+
+    /// Move the changes of the pre-releases of `version` into the unreleased
+    /// changes. Returns whether there were any pre-releases to fold.
+    fn fold_pre_releases(&mut self, version: &SemanticVersion, fold: PreReleaseFold) -> bool {
+        let is_pre_release_of = |rel: &Release| {
+            rel.title.semver().is_some_and(|pre| {
+                pre.is_pre_release()
+                    && (pre.major(), pre.minor(), pre.patch())
+                        == (version.major(), version.minor(), version.patch())
+            })
+        };
+        if version.is_pre_release() || !self.releases.iter().any(is_pre_release_of) {
+            return false;
+        }
+
+        let releases = &self.releases;
+        self.unreleased.edit_changes(|changes| {
+            for release in releases.iter().filter(|rel| is_pre_release_of(rel)) {
+                changes.merge(&release.changes());
+            }
+        });
+        match fold {
+            PreReleaseFold::Remove => self.releases.retain(|rel| !is_pre_release_of(rel)),
+            PreReleaseFold::Collapse => self
+                .releases
+                .iter_mut()
+                .filter(|rel| is_pre_release_of(rel))
+                .for_each(|rel| rel.content = None),
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BumpError, ChangelogBuilder, Unreleased};
+
+    use super::*;
+
+    fn changelog<'c>(content: &'c str, versions: &[&'c str]) -> Changelog<'c> {
+        let releases = versions
+            .iter()
+            .map(|&version| Release::new(version, "2022-10-03"))
+            .collect();
+        ChangelogBuilder::new()
+            .unreleased(Unreleased::with("https://a.b/compare", content))
+            .releases(releases)
+            .build()
+    }
+
+    fn date() -> ReleaseDate {
+        ReleaseDate::new(2022, 10, 20).expect("valid date")
+    }
+
+    #[test]
+    fn release_moves_unreleased_content() {
+        let mut changelog = changelog("### Added\n\n- Feature.", &["1.0.0", "0.1.0"]);
+        let release = changelog
+            .release(NextVersion::Minor, date())
+            .expect("valid release");
+        assert_eq!("1.1.0", release.title_string());
+        assert_eq!(Some("2022-10-20"), release.date());
+        assert_eq!(Some("### Added\n\n- Feature."), release.content());
+        assert_eq!(None, release.url());
+
+        let titles: Vec<_> = changelog
+            .releases()
+            .iter()
+            .map(Release::title_string)
+            .collect();
+        assert_eq!(vec!["1.1.0", "1.0.0", "0.1.0"], titles);
+        assert_eq!(None, changelog.unreleased().content());
+        assert_eq!(Some("https://a.b/compare"), changelog.unreleased().url());
+    }
+
+    #[test]
+    fn release_first_version() {
+        let cases = [
+            ("0.0.1", NextVersion::Patch),
+            ("0.1.0", NextVersion::Minor),
+            ("1.0.0", NextVersion::Major),
+            ("0.1.0", NextVersion::Auto),
+        ];
+        for (expected, next) in cases {
+            let mut changelog = changelog("### Added\n\n- Feature.", &[]);
+            let release = changelog.release(next, date()).expect("valid release");
+            assert_eq!(expected, release.title_string());
+        }
+    }
+
+    #[test]
+    fn release_with_cargo_policy() {
+        let mut changelog = changelog("### Changed\n\n- [**BREAKING**] Api.", &["0.2.0"]);
+        let options = ReleaseOptions::new().policy(BumpPolicy::Cargo);
+        let release = changelog
+            .release_with(NextVersion::Auto, date(), &options)
+            .expect("valid release");
+        assert_eq!("0.3.0", release.title_string());
+    }
+
+    #[test]
+    fn release_with_repo_links() {
+        let mut changelog = changelog("- Change.", &["1.0.0"]);
+        let links = RepoLinks::new("https://gitlab.com/group/sub/repo");
+        let options = ReleaseOptions::new().repo_links(links);
+        let release = changelog
+            .release_with(NextVersion::Patch, date(), &options)
+            .expect("valid release");
+        assert_eq!(
+            Some("https://gitlab.com/group/sub/repo/-/compare/v1.0.0...v1.0.1"),
+            release.url()
+        );
+        assert_eq!(
+            Some("https://gitlab.com/group/sub/repo/-/compare/v1.0.1...HEAD"),
+            changelog.unreleased().url()
+        );
+    }
+
+    #[test]
+    fn release_with_bitbucket_links_keeps_compare_style() {
+        let repo = "https://bitbucket.org/team/repo";
+        let mut changelog = changelog("- Change.", &["1.0.0"]);
+        changelog.releases[0].set_url(format!("{}/branches/compare/v1.0.0%0Dv0.9.0", repo));
+        let options = ReleaseOptions::new().repo_links(RepoLinks::new(repo));
+        for version in ["1.0.1", "1.0.2"] {
+            changelog.unreleased.set_content("- Change.");
+            let release = changelog
+                .release_with(NextVersion::Patch, date(), &options)
+                .expect("valid release");
+            assert_eq!(version, release.title_string());
+        }
+
+        let urls: Vec<_> = changelog
+            .releases()
+            .iter()
+            .map(|rel| rel.url().map(str::to_owned))
+            .collect();
+        assert_eq!(
+            vec![
+                Some(format!("{}/branches/compare/v1.0.2%0Dv1.0.1", repo)),
+                Some(format!("{}/branches/compare/v1.0.1%0Dv1.0.0", repo)),
+                Some(format!("{}/branches/compare/v1.0.0%0Dv0.9.0", repo)),
+            ],
+            urls
+        );
+    }
+
+    #[test]
+    fn release_with_tag_format() {
+        let mut changelog = changelog("- Change.", &["1.0.0"]);
+        let options = ReleaseOptions::new()
+            .repo_links(RepoLinks::new("https://github.com/sonro/chog"))
+            .tag_format(TagFormat::package("chog-cli"));
+        let release = changelog
+            .release_with(NextVersion::Minor, date(), &options)
+            .expect("valid release");
+        assert_eq!(
+            Some("https://github.com/sonro/chog/compare/chog-cli-v1.0.0...chog-cli-v1.1.0"),
+            release.url()
+        );
+    }
+
+    fn pre_releases_changelog() -> Changelog<'static> {
+        let mut changelog = changelog(
+            "### Fixed\n\n- Last bug.",
+            &["1.0.0-rc.1", "1.0.0-beta.1", "0.9.0"],
+        );
+        changelog.releases[0].set_content("### Fixed\n\n- Bug.");
+        changelog.releases[1].set_content("### Added\n\n- Feature.\n\n### Fixed\n\n- Bug.");
+        changelog.releases[2].set_content("### Added\n\n- Old feature.");
+        changelog
+    }
+
+    #[test]
+    fn release_folds_and_removes_pre_releases() {
+        let mut changelog = pre_releases_changelog();
+        let options = ReleaseOptions::new().fold_pre_releases(PreReleaseFold::Remove);
+        let release = changelog
+            .release_with(NextVersion::Release, date(), &options)
+            .expect("valid release");
+        assert_eq!("1.0.0", release.title_string());
+        assert_eq!(
+            Some("### Added\n\n- Feature.\n\n### Fixed\n\n- Last bug.\n- Bug."),
+            release.content()
+        );
+        let titles: Vec<_> = changelog
+            .releases()
+            .iter()
+            .map(Release::title_string)
+            .collect();
+        assert_eq!(vec!["1.0.0", "0.9.0"], titles);
+    }
+
+    #[test]
+    fn release_folds_and_collapses_pre_releases() {
+        let mut changelog = pre_releases_changelog();
+        let options = ReleaseOptions::new().fold_pre_releases(PreReleaseFold::Collapse);
+        changelog
+            .release_with(NextVersion::Release, date(), &options)
+            .expect("valid release");
+        let releases: Vec<_> = changelog
+            .releases()
+            .iter()
+            .map(|rel| (rel.title_string(), rel.content()))
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    "1.0.0".to_string(),
+                    Some("### Added\n\n- Feature.\n\n### Fixed\n\n- Last bug.\n- Bug.")
+                ),
+                ("1.0.0-rc.1".to_string(), None),
+                ("1.0.0-beta.1".to_string(), None),
+                ("0.9.0".to_string(), Some("### Added\n\n- Old feature.")),
+            ],
+            releases
+        );
+    }
+
+    #[test]
+    fn release_folded_compares_from_stable_release() {
+        for fold in [PreReleaseFold::Remove, PreReleaseFold::Collapse] {
+            let mut changelog = pre_releases_changelog();
+            changelog.unreleased.url = Some("https://a.b/compare/v1.0.0-rc.1...HEAD".into());
+            let options = ReleaseOptions::new().fold_pre_releases(fold);
+            let release = changelog
+                .release_with(NextVersion::Release, date(), &options)
+                .expect("valid release");
+            assert_eq!(Some("https://a.b/compare/v0.9.0...v1.0.0"), release.url());
+            assert_eq!(
+                Some("https://a.b/compare/v1.0.0...HEAD"),
+                changelog.unreleased.url()
+            );
+        }
+    }
+
+    #[test]
+    fn release_pre_release_does_not_fold() {
+        let mut changelog = pre_releases_changelog();
+        let options = ReleaseOptions::new().fold_pre_releases(PreReleaseFold::Remove);
+        let release = changelog
+            .release_with(NextVersion::PreRelease(None), date(), &options)
+            .expect("valid release");
+        assert_eq!("1.0.0-rc.2", release.title_string());
+        assert_eq!(Some("### Fixed\n\n- Last bug."), release.content());
+        assert_eq!(4, changelog.releases().len());
+    }
+
+    #[test]
+    fn release_without_changes_errors() {
+        for content in ["", "### Added\n\n### Fixed\n\n#### Lib"] {
+            let mut changelog = changelog(content, &["1.0.0"]);
+            let err = changelog
+                .release(NextVersion::Patch, date())
+                .expect_err("no changes");
+            assert_eq!(ReleaseError::NoChanges, err, "content: {:?}", content);
+        }
+    }
+
+    #[test]
+    fn release_custom_version_not_greater_errors() {
+        let mut changelog = changelog("- Change.", &["1.0.0", "0.1.0"]);
+        let next = NextVersion::Custom(SemanticVersion::new(0, 2, 0).into());
+        let err = changelog.release(next, date()).expect_err("not greater");
+        let expected = ReleaseError::Bump(BumpError::NotGreater {
+            current: "1.0.0".into(),
+            next: "0.2.0".into(),
+        });
+        assert_eq!(expected, err);
+        assert_eq!(Some("- Change."), changelog.unreleased().content());
+    }
+
+    #[test]
+    fn release_version_exists_errors() {
+        let mut changelog = changelog("- Change.", &["1.0.0", "1.0.0-rc.1"]);
+        // build metadata is not part of the version
+        for version in ["1.0.0", "1.0.0-rc.1", "1.0.0+build.5"] {
+            let next = NextVersion::Custom(ReleaseTitle::version(version).expect("valid version"));
+            let err = changelog.release(next, date()).expect_err("exists");
+            assert_eq!(ReleaseError::VersionExists(version.into()), err);
+        }
+        assert_eq!(Some("- Change."), changelog.unreleased().content());
+    }
+}
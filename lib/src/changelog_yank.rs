@@ -0,0 +1,76 @@
+use crate::{Changelog, Release, ReleaseTitle, UnknownVersion};
+
+impl<'c> Changelog<'c> {
+    /// Mark the release of `version` as `[YANKED]`.
+    pub fn yank(&mut self, version: &ReleaseTitle) -> Result<&Release<'c>, UnknownVersion> {
+        self.set_yanked(version, true)
+    }
+
+    /// Remove the `[YANKED]` mark from the release of `version`.
+    pub fn unyank(&mut self, version: &ReleaseTitle) -> Result<&Release<'c>, UnknownVersion> {
+        self.set_yanked(version, false)
+    }
+
+    fn set_yanked(
+        &mut self,
+        version: &ReleaseTitle,
+        yanked: bool,
+    ) -> Result<&Release<'c>, UnknownVersion> {
+        let release = self
+            .releases
+            .iter_mut()
+            .find(|rel| &rel.title == version)
+            .ok_or_else(|| UnknownVersion(version.to_string()))?;
+        release.yanked = yanked;
+        Ok(release)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChangelogBuilder;
+
+    use super::*;
+
+    fn changelog() -> Changelog<'static> {
+        ChangelogBuilder::new()
+            .add_release(Release::new("1.0.0", "2022-10-03"))
+            .add_release(Release::new("0.1.0", "2022-09-01"))
+            .build()
+    }
+
+    #[test]
+    fn yank_and_unyank() {
+        let mut changelog = changelog();
+        let version = ReleaseTitle::from("0.1.0");
+        let release = changelog.yank(&version).expect("known version");
+        assert!(release.is_yanked());
+        assert!(!changelog.releases()[0].is_yanked());
+        assert!(changelog.releases()[1].is_yanked());
+
+        let release = changelog.unyank(&version).expect("known version");
+        assert!(!release.is_yanked());
+    }
+
+    #[test]
+    fn yank_unknown_version_errors() {
+        let mut changelog = changelog();
+        let err = changelog
+            .yank(&ReleaseTitle::from("0.2.0"))
+            .expect_err("unknown version");
+        assert_eq!(UnknownVersion("0.2.0".into()), err);
+    }
+
+    #[test]
+    fn yank_numeric_version() {
+        let mut changelog = changelog();
+        changelog
+            .releases
+            .insert(0, Release::new("1.0.0.1", "2022-10-04"));
+        let release = changelog
+            .yank(&ReleaseTitle::from("1.0.0.1"))
+            .expect("known version");
+        assert_eq!("1.0.0.1", release.title_string());
+        assert!(release.is_yanked());
+    }
+}
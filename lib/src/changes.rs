@@ -0,0 +1,717 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    entry::{fmt_entries, parse_entries},
+    util::optcow,
+    BreakingMarkers, ChangeKind, Changes, Entry, Group, Release, Section,
+};
+
+impl<'c> Release<'c> {
+    /// Structured view of the release content.
+    pub fn changes(&self) -> Changes<'_> {
+        self.changes_with(&BreakingMarkers::default())
+    }
+
+    /// Structured view of the release content, classifying section headings
+    /// after any of the breaking change `markers`.
+    pub fn changes_with(&self, markers: &BreakingMarkers) -> Changes<'_> {
+        match &self.content {
+            Some(content) => Changes::parse_with(content, markers),
+            None => Changes::default(),
+        }
+    }
+
+    /// Change sections in the order they appear in the release content.
+    pub fn sections(&self) -> impl Iterator<Item = Section<'_>> {
+        self.changes().sections.into_iter()
+    }
+
+    /// Edit the structured view of the release content.
+    ///
+    /// The content is rewritten from the edited [`Changes`] afterwards.
+    pub fn edit_changes<F, R>(&mut self, edit: F) -> R
+    where
+        F: FnOnce(&mut Changes<'c>) -> R,
+    {
+        let mut changes = match &self.content {
+            Some(Cow::Borrowed(content)) => Changes::parse(content),
+            Some(Cow::Owned(content)) => Changes::parse(content).to_owned(),
+            None => Changes::default(),
+        };
+        let output = edit(&mut changes);
+        self.content = optcow(changes.to_string());
+        output
+    }
+
+    /// Add an entry to the end of the section of `kind`.
+    ///
+    /// The section is created if it does not exist yet.
+    pub fn add_entry<T: Into<Entry<'c>>>(&mut self, kind: ChangeKind<'c>, entry: T) {
+        self.edit_changes(|changes| changes.section_or_insert(kind).push_entry(entry));
+    }
+
+    /// Add an entry to the end of the named group inside the section of
+    /// `kind`, for example `#### Lib` under `### Added`.
+    ///
+    /// The section and group are created if they do not exist yet.
+    pub fn add_group_entry<G, T>(&mut self, kind: ChangeKind<'c>, group: G, entry: T)
+    where
+        G: Into<Cow<'c, str>>,
+        T: Into<Entry<'c>>,
+    {
+        self.edit_changes(|changes| {
+            changes
+                .section_or_insert(kind)
+                .group_or_insert(group)
+                .push_entry(entry)
+        });
+    }
+
+    /// Remove the entry at `index` from the section of `kind`.
+    ///
+    /// Returns the text of the removed entry.
+    pub fn remove_entry(&mut self, kind: &ChangeKind, index: usize) -> Option<String> {
+        self.edit_changes(|changes| {
+            let entry = changes.section_mut(kind)?.remove_entry(index)?;
+            Some(entry.text().to_owned())
+        })
+    }
+}
+
+impl<'c> Changes<'c> {
+    /// Parse release content into its sections.
+    pub fn parse(content: &'c str) -> Self {
+        Self::parse_with(content, &BreakingMarkers::default())
+    }
+
+    /// Parse release content into its sections, ignoring any of the breaking
+    /// change `markers` when classifying section headings.
+    pub fn parse_with(content: &'c str, markers: &BreakingMarkers) -> Self {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut headings = Vec::new();
+        let mut in_fence = false;
+        for (i, line) in lines.iter().enumerate() {
+            if is_fence(line) {
+                in_fence = !in_fence;
+            } else if !in_fence && line.starts_with("### ") {
+                headings.push(i);
+            }
+        }
+
+        let intro_end = headings.first().copied().unwrap_or(lines.len());
+        let intro = parse_entries(content, &lines[..intro_end]);
+
+        let sections = headings
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| {
+                let end = headings.get(i + 1).copied().unwrap_or(lines.len());
+                let heading = lines[start]["### ".len()..].trim();
+                let (entries, groups) = parse_section_body(content, &lines[start + 1..end]);
+                Section {
+                    kind: ChangeKind::from_heading(heading, markers),
+                    heading: Cow::Borrowed(heading),
+                    entries,
+                    groups,
+                }
+            })
+            .collect();
+
+        Self { intro, sections }
+    }
+
+    /// Entries before the first section heading.
+    pub fn intro(&self) -> &[Entry<'c>] {
+        &self.intro
+    }
+
+    pub fn intro_mut(&mut self) -> &mut Vec<Entry<'c>> {
+        &mut self.intro
+    }
+
+    pub fn sections(&self) -> &[Section<'c>] {
+        &self.sections
+    }
+
+    pub fn sections_mut(&mut self) -> &mut Vec<Section<'c>> {
+        &mut self.sections
+    }
+
+    /// First section of the given kind.
+    pub fn section(&self, kind: &ChangeKind) -> Option<&Section<'c>> {
+        self.sections.iter().find(|section| &section.kind == kind)
+    }
+
+    /// First section of the given kind.
+    pub fn section_mut(&mut self, kind: &ChangeKind) -> Option<&mut Section<'c>> {
+        self.sections
+            .iter_mut()
+            .find(|section| &section.kind == kind)
+    }
+
+    /// First section of the given kind, inserting a new empty section if it
+    /// does not exist.
+    ///
+    /// New sections are placed before the first section that comes after it
+    /// in the [`ChangeKind`] order.
+    pub fn section_or_insert(&mut self, kind: ChangeKind<'c>) -> &mut Section<'c> {
+        let index = match self.sections.iter().position(|s| s.kind == kind) {
+            Some(index) => index,
+            None => {
+                let index = self
+                    .sections
+                    .iter()
+                    .position(|s| s.kind.rank() > kind.rank())
+                    .unwrap_or(self.sections.len());
+                self.sections.insert(index, Section::new(kind));
+                index
+            }
+        };
+        &mut self.sections[index]
+    }
+
+    /// Remove the first section of the given kind.
+    pub fn remove_section(&mut self, kind: &ChangeKind) -> Option<Section<'c>> {
+        let index = self.sections.iter().position(|s| &s.kind == kind)?;
+        Some(self.sections.remove(index))
+    }
+
+    /// Whether there are no entries, sections without entries do not count.
+    pub fn is_empty(&self) -> bool {
+        self.intro.is_empty() && self.sections.iter().all(Section::is_empty)
+    }
+
+    /// Add the entries of `other` that are not here yet.
+    ///
+    /// Entries go to the section of the same kind and the group of the same
+    /// name, which are created if needed. Entries are compared by their text,
+    /// so each change is only listed once.
+    pub fn merge(&mut self, other: &Changes) {
+        push_missing(&mut self.intro, &other.intro);
+        for section in &other.sections {
+            let exists = self.section(&section.kind).is_some();
+            let target = self.section_or_insert(section.kind.to_owned());
+            if !exists {
+                // keep any breaking change marker of the heading
+                target.heading = Cow::Owned(section.heading.to_string());
+            }
+            push_missing(&mut target.entries, &section.entries);
+            for group in &section.groups {
+                let target = target.group_or_insert(group.name.to_string());
+                push_missing(&mut target.entries, &group.entries);
+            }
+        }
+    }
+
+    pub fn to_owned(&self) -> Changes<'static> {
+        Changes {
+            intro: self.intro.iter().map(Entry::to_owned).collect(),
+            sections: self.sections.iter().map(Section::to_owned).collect(),
+        }
+    }
+}
+
+impl<'c> Section<'c> {
+    /// Create an empty section with the standard heading for `kind`.
+    pub fn new(kind: ChangeKind<'c>) -> Self {
+        Self {
+            heading: Cow::Owned(kind.to_string()),
+            kind,
+            entries: Vec::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    pub fn kind(&self) -> &ChangeKind<'c> {
+        &self.kind
+    }
+
+    /// Heading text as written in the changelog.
+    pub fn heading(&self) -> &str {
+        &self.heading
+    }
+
+    /// Entries outside of any group.
+    pub fn entries(&self) -> &[Entry<'c>] {
+        &self.entries
+    }
+
+    pub fn entries_mut(&mut self) -> &mut Vec<Entry<'c>> {
+        &mut self.entries
+    }
+
+    /// All entries, including those in groups.
+    pub fn all_entries(&self) -> impl Iterator<Item = &Entry<'c>> {
+        self.entries
+            .iter()
+            .chain(self.groups.iter().flat_map(|group| group.entries.iter()))
+    }
+
+    /// Add an entry to the end of the section, before any group.
+    pub fn push_entry<T: Into<Entry<'c>>>(&mut self, entry: T) {
+        self.entries.push(entry.into());
+    }
+
+    /// Remove the entry at `index`, outside of any group.
+    pub fn remove_entry(&mut self, index: usize) -> Option<Entry<'c>> {
+        remove_entry(&mut self.entries, index)
+    }
+
+    /// Sub-groups of entries in the order they appear.
+    pub fn groups(&self) -> &[Group<'c>] {
+        &self.groups
+    }
+
+    pub fn groups_mut(&mut self) -> &mut Vec<Group<'c>> {
+        &mut self.groups
+    }
+
+    /// Group with the given name, ignoring ASCII case.
+    pub fn group(&self, name: &str) -> Option<&Group<'c>> {
+        self.groups.iter().find(|group| group.is_named(name))
+    }
+
+    /// Group with the given name, ignoring ASCII case.
+    pub fn group_mut(&mut self, name: &str) -> Option<&mut Group<'c>> {
+        self.groups.iter_mut().find(|group| group.is_named(name))
+    }
+
+    /// Group with the given name, adding a new empty group to the end of the
+    /// section if it does not exist.
+    pub fn group_or_insert<T: Into<Cow<'c, str>>>(&mut self, name: T) -> &mut Group<'c> {
+        let name = name.into();
+        let index = match self.groups.iter().position(|g| g.is_named(&name)) {
+            Some(index) => index,
+            None => {
+                self.groups.push(Group::new(name));
+                self.groups.len() - 1
+            }
+        };
+        &mut self.groups[index]
+    }
+
+    /// Remove the group with the given name.
+    pub fn remove_group(&mut self, name: &str) -> Option<Group<'c>> {
+        let index = self.groups.iter().position(|g| g.is_named(name))?;
+        Some(self.groups.remove(index))
+    }
+
+    /// Whether the section has no entries, including in its groups.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.groups.iter().all(Group::is_empty)
+    }
+
+    pub fn to_owned(&self) -> Section<'static> {
+        Section {
+            kind: self.kind.to_owned(),
+            heading: Cow::Owned(self.heading.clone().into_owned()),
+            entries: self.entries.iter().map(Entry::to_owned).collect(),
+            groups: self.groups.iter().map(Group::to_owned).collect(),
+        }
+    }
+}
+
+impl<'c> Group<'c> {
+    /// Create an empty group.
+    pub fn new<T: Into<Cow<'c, str>>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn entries(&self) -> &[Entry<'c>] {
+        &self.entries
+    }
+
+    pub fn entries_mut(&mut self) -> &mut Vec<Entry<'c>> {
+        &mut self.entries
+    }
+
+    /// Add an entry to the end of the group.
+    pub fn push_entry<T: Into<Entry<'c>>>(&mut self, entry: T) {
+        self.entries.push(entry.into());
+    }
+
+    /// Remove the entry at `index`.
+    pub fn remove_entry(&mut self, index: usize) -> Option<Entry<'c>> {
+        remove_entry(&mut self.entries, index)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn to_owned(&self) -> Group<'static> {
+        Group {
+            name: Cow::Owned(self.name.clone().into_owned()),
+            entries: self.entries.iter().map(Entry::to_owned).collect(),
+        }
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+}
+
+impl<'c> ChangeKind<'c> {
+    /// Standard Keep a Changelog kinds in their recommended order.
+    pub const STANDARD: [ChangeKind<'static>; 6] = [
+        ChangeKind::Added,
+        ChangeKind::Changed,
+        ChangeKind::Deprecated,
+        ChangeKind::Removed,
+        ChangeKind::Fixed,
+        ChangeKind::Security,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Added => "Added",
+            Self::Changed => "Changed",
+            Self::Deprecated => "Deprecated",
+            Self::Removed => "Removed",
+            Self::Fixed => "Fixed",
+            Self::Security => "Security",
+            Self::Custom(name) => name,
+        }
+    }
+
+    /// Kind of a section heading, ignoring ASCII case and any of the breaking
+    /// change `markers`.
+    pub fn from_heading(heading: &'c str, markers: &BreakingMarkers) -> Self {
+        let heading = markers.strip(heading).unwrap_or(heading).trim();
+        ChangeKind::STANDARD
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(heading))
+            .unwrap_or(ChangeKind::Custom(Cow::Borrowed(heading)))
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    pub fn to_owned(&self) -> ChangeKind<'static> {
+        match self {
+            Self::Added => ChangeKind::Added,
+            Self::Changed => ChangeKind::Changed,
+            Self::Deprecated => ChangeKind::Deprecated,
+            Self::Removed => ChangeKind::Removed,
+            Self::Fixed => ChangeKind::Fixed,
+            Self::Security => ChangeKind::Security,
+            Self::Custom(name) => ChangeKind::Custom(Cow::Owned(name.clone().into_owned())),
+        }
+    }
+
+    /// Position in the standard order, custom kinds come last.
+    pub(crate) fn rank(&self) -> usize {
+        ChangeKind::STANDARD
+            .iter()
+            .position(|kind| kind == self)
+            .unwrap_or(ChangeKind::STANDARD.len())
+    }
+}
+
+/// Kind of a section heading, ignoring ASCII case and any default breaking
+/// change marker.
+impl<'c> From<&'c str> for ChangeKind<'c> {
+    fn from(heading: &'c str) -> Self {
+        Self::from_heading(heading, &BreakingMarkers::default())
+    }
+}
+
+impl<'c> fmt::Display for ChangeKind<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'c> fmt::Display for Changes<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_entries(f, &self.intro)?;
+        let mut separate = !self.intro.is_empty();
+        for section in &self.sections {
+            if separate {
+                f.write_str("\n\n")?;
+            }
+            section.fmt(f)?;
+            separate = true;
+        }
+        Ok(())
+    }
+}
+
+impl<'c> fmt::Display for Section<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "### {}", self.heading)?;
+        if !self.entries.is_empty() {
+            f.write_str("\n\n")?;
+            fmt_entries(f, &self.entries)?;
+        }
+        for group in &self.groups {
+            f.write_str("\n\n")?;
+            group.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'c> fmt::Display for Group<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#### {}", self.name)?;
+        if !self.entries.is_empty() {
+            f.write_str("\n\n")?;
+        }
+        fmt_entries(f, &self.entries)
+    }
+}
+
+/// Split the lines of a section into its own entries and its groups.
+fn parse_section_body<'c>(content: &'c str, lines: &[&'c str]) -> (Vec<Entry<'c>>, Vec<Group<'c>>) {
+    let mut headings = Vec::new();
+    let mut in_fence = false;
+    for (i, line) in lines.iter().enumerate() {
+        if is_fence(line) {
+            in_fence = !in_fence;
+        } else if !in_fence && line.starts_with("#### ") {
+            headings.push(i);
+        }
+    }
+
+    let entries_end = headings.first().copied().unwrap_or(lines.len());
+    let entries = parse_entries(content, &lines[..entries_end]);
+
+    let groups = headings
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = headings.get(i + 1).copied().unwrap_or(lines.len());
+            Group {
+                name: Cow::Borrowed(lines[start]["#### ".len()..].trim()),
+                entries: parse_entries(content, &lines[start + 1..end]),
+            }
+        })
+        .collect();
+
+    (entries, groups)
+}
+
+/// Push copies of the `others` not in `entries` yet.
+fn push_missing(entries: &mut Vec<Entry>, others: &[Entry]) {
+    for entry in others {
+        if !entries
+            .iter()
+            .any(|e| e.text().trim() == entry.text().trim())
+        {
+            entries.push(entry.to_owned());
+        }
+    }
+}
+
+fn is_fence(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("```") || line.starts_with("~~~")
+}
+
+fn remove_entry<'c>(entries: &mut Vec<Entry<'c>>, index: usize) -> Option<Entry<'c>> {
+    match index < entries.len() {
+        true => Some(entries.remove(index)),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts<'a>(entries: &'a [Entry]) -> Vec<&'a str> {
+        entries.iter().map(Entry::text).collect()
+    }
+
+    const CONTENT: &str = "- Stabilized API\n\n\
+        ### Added\n\n\
+        - First.\n\
+        - Second wraps\n  onto the next line.\n\n\
+        ### Documentation\n\n\
+        - More examples.";
+
+    #[test]
+    fn parse_sections() {
+        let changes = Changes::parse(CONTENT);
+        assert_eq!(vec!["Stabilized API"], texts(changes.intro()));
+
+        let kinds: Vec<_> = changes.sections().iter().map(Section::kind).collect();
+        assert_eq!(
+            vec![
+                &ChangeKind::Added,
+                &ChangeKind::Custom("Documentation".into())
+            ],
+            kinds
+        );
+
+        let added = texts(changes.sections()[0].entries());
+        assert_eq!(vec!["First.", "Second wraps\n  onto the next line."], added);
+    }
+
+    #[test]
+    fn render_same_as_content() {
+        assert_eq!(CONTENT, Changes::parse(CONTENT).to_string());
+    }
+
+    #[test]
+    fn change_kind_from_heading() {
+        let cases = [
+            (ChangeKind::Added, "Added"),
+            (ChangeKind::Changed, "changed"),
+            (ChangeKind::Deprecated, "DEPRECATED"),
+            (ChangeKind::Removed, " Removed "),
+            (ChangeKind::Fixed, "Fixed"),
+            (ChangeKind::Security, "Security"),
+            (ChangeKind::Custom("Documentation".into()), "Documentation"),
+            (
+                ChangeKind::Custom("Project changes".into()),
+                "[**BREAKING**] Project changes",
+            ),
+            (ChangeKind::Removed, "[**BREAKING CHANGE**] Removed"),
+        ];
+        for (expected, heading) in cases {
+            assert_eq!(expected, ChangeKind::from(heading));
+        }
+    }
+
+    #[test]
+    fn change_kind_with_custom_markers() {
+        let markers = BreakingMarkers::with(["BREAKING:"]);
+        assert_eq!(
+            ChangeKind::Removed,
+            ChangeKind::from_heading("BREAKING: Removed", &markers)
+        );
+        assert_eq!(
+            ChangeKind::Custom("[**BREAKING**] Removed".into()),
+            ChangeKind::from_heading("[**BREAKING**] Removed", &markers)
+        );
+
+        let changes = Changes::parse_with(
+            "### BREAKING: removed
+
+- Api.",
+            &markers,
+        );
+        assert_eq!(&ChangeKind::Removed, changes.sections()[0].kind());
+    }
+
+    #[test]
+    fn section_or_insert_in_standard_order() {
+        let mut changes = Changes::parse("### Fixed\n\n- Bug.");
+        changes
+            .section_or_insert(ChangeKind::Added)
+            .push_entry("Feature.");
+        changes
+            .section_or_insert(ChangeKind::Custom("Documentation".into()))
+            .push_entry("Docs.");
+        changes
+            .section_or_insert(ChangeKind::Fixed)
+            .push_entry("Bug 2.");
+        assert_eq!(
+            "### Added\n\n- Feature.\n\n\
+            ### Fixed\n\n- Bug.\n- Bug 2.\n\n\
+            ### Documentation\n\n- Docs.",
+            changes.to_string()
+        );
+    }
+
+    const GROUPED: &str = "### Added\n\n\
+        - Shared.\n\n\
+        #### Cli\n\n\
+        - Argument handling.\n\n\
+        #### Lib\n\n\
+        - `NextVersion` type.\n\
+        - `SemanticVersion` type.";
+
+    #[test]
+    fn parse_groups() {
+        let changes = Changes::parse(GROUPED);
+        let added = changes.section(&ChangeKind::Added).expect("added section");
+        assert_eq!(vec!["Shared."], texts(added.entries()));
+
+        let names: Vec<_> = added.groups().iter().map(Group::name).collect();
+        assert_eq!(vec!["Cli", "Lib"], names);
+
+        let lib = added.group("lib").expect("lib group");
+        assert_eq!(
+            vec!["`NextVersion` type.", "`SemanticVersion` type."],
+            texts(lib.entries())
+        );
+        assert_eq!(4, added.all_entries().count());
+    }
+
+    #[test]
+    fn render_groups() {
+        assert_eq!(GROUPED, Changes::parse(GROUPED).to_string());
+    }
+
+    #[test]
+    fn group_or_insert_appends() {
+        let mut changes = Changes::parse(GROUPED);
+        let added = changes.section_mut(&ChangeKind::Added).expect("added");
+        added.group_or_insert("Lib").push_entry("`Changes` type.");
+        added.group_or_insert("Docs").push_entry("Examples.");
+        added.push_entry("Also shared.");
+        assert_eq!(
+            "### Added\n\n\
+            - Shared.\n\
+            - Also shared.\n\n\
+            #### Cli\n\n\
+            - Argument handling.\n\n\
+            #### Lib\n\n\
+            - `NextVersion` type.\n\
+            - `SemanticVersion` type.\n\
+            - `Changes` type.\n\n\
+            #### Docs\n\n\
+            - Examples.",
+            changes.to_string()
+        );
+    }
+
+    #[test]
+    fn reorder_and_edit_entries() {
+        let mut changes = Changes::parse("### Fixed\n\n- B.\n- A.\n- C.");
+        let fixed = changes.section_mut(&ChangeKind::Fixed).expect("fixed");
+        fixed.entries_mut().sort_by(|l, r| l.text().cmp(r.text()));
+        fixed.entries_mut()[2].set_text("C, edited.");
+        assert_eq!(Some("A."), fixed.remove_entry(0).as_ref().map(Entry::text));
+        assert_eq!("### Fixed\n\n- B.\n- C, edited.", changes.to_string());
+    }
+
+    #[test]
+    fn merge_adds_missing_entries_by_kind() {
+        let mut changes = Changes::parse("### Fixed\n\n- Bug.\n\n#### Lib\n\n- Parser.");
+        let other = Changes::parse(
+            "- Intro.\n\n\
+            ### [**BREAKING**] Project changes\n\n- Renamed.\n\n\
+            ### Fixed\n\n- Bug.\n- Other bug.\n\n#### Lib\n\n- Parser.\n- Render.\n\n\
+            ### Added\n\n- Feature.",
+        );
+        changes.merge(&other);
+        assert_eq!(
+            "- Intro.\n\n\
+            ### Added\n\n- Feature.\n\n\
+            ### Fixed\n\n- Bug.\n- Other bug.\n\n#### Lib\n\n- Parser.\n- Render.\n\n\
+            ### [**BREAKING**] Project changes\n\n- Renamed.",
+            changes.to_string()
+        );
+    }
+
+    #[test]
+    fn paragraphs_kept_as_entries() {
+        let content = "### Added\n\nSome notes\nover two lines.\n\n- Entry.";
+        let changes = Changes::parse(content);
+        let entries = texts(changes.sections()[0].entries());
+        assert_eq!(vec!["Some notes\nover two lines.", "Entry."], entries);
+        assert_eq!(content, changes.to_string());
+    }
+}
@@ -0,0 +1,240 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{util::span, Entry};
+
+impl<'c> Entry<'c> {
+    /// Create a list entry from its text.
+    ///
+    /// Continuation lines are indented to line up with the first line.
+    pub fn new<T: Into<Cow<'c, str>>>(text: T) -> Self {
+        let text = text.into();
+        let mut source = String::with_capacity(text.len() + 2);
+        for (i, line) in text.lines().enumerate() {
+            match i {
+                0 => source.push_str("- "),
+                _ if line.is_empty() || line.starts_with(char::is_whitespace) => source.push('\n'),
+                _ => source.push_str("\n  "),
+            }
+            source.push_str(line);
+        }
+        if source.is_empty() {
+            source.push('-');
+        }
+        Self {
+            source: Cow::Owned(source),
+        }
+    }
+
+    /// The entry as written in the changelog, including the list marker.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Entry text without the list marker.
+    ///
+    /// Includes any continuation lines and nested list items.
+    pub fn text(&self) -> &str {
+        match is_list_item(&self.source) {
+            true => &self.source[2..],
+            false => &self.source,
+        }
+    }
+
+    /// Replace the entry with a new list entry.
+    pub fn set_text<T: Into<Cow<'c, str>>>(&mut self, text: T) {
+        *self = Self::new(text);
+    }
+
+    /// Whether the entry is a list item rather than a paragraph.
+    pub fn is_list_item(&self) -> bool {
+        is_list_item(&self.source)
+    }
+
+    /// List items nested under this entry.
+    pub fn nested(&self) -> Vec<Entry<'_>> {
+        let lines: Vec<&str> = self.source.lines().skip(1).collect();
+        let indent = lines
+            .iter()
+            .filter(|line| line.starts_with(char::is_whitespace))
+            .filter(|line| is_list_item(line.trim_start()))
+            .map(|line| indent_len(line))
+            .min();
+        let indent = match indent {
+            Some(indent) => indent,
+            None => return Vec::new(),
+        };
+
+        let mut nested = Vec::new();
+        let mut current: Option<(&str, &str)> = None;
+        for &line in &lines {
+            if indent_len(line) == indent && is_list_item(&line[indent..]) {
+                if let Some((first, last)) = current.take() {
+                    nested.push(Entry::borrowed(span(&self.source, first, last)));
+                }
+                current = Some((&line[indent..], line));
+            } else if let Some((_, last)) = current.as_mut() {
+                if !line.trim().is_empty() {
+                    *last = line;
+                }
+            }
+        }
+        if let Some((first, last)) = current {
+            nested.push(Entry::borrowed(span(&self.source, first, last)));
+        }
+        nested
+    }
+
+    pub fn to_owned(&self) -> Entry<'static> {
+        Entry {
+            source: Cow::Owned(self.source.clone().into_owned()),
+        }
+    }
+
+    fn borrowed(source: &'c str) -> Self {
+        Self {
+            source: Cow::Borrowed(source),
+        }
+    }
+}
+
+impl<'c> From<&'c str> for Entry<'c> {
+    fn from(text: &'c str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Entry<'static> {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<'c> fmt::Display for Entry<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Split lines of content into entries.
+///
+/// An entry is either a list item with all of its continuation lines and
+/// nested items, or any other paragraph of text.
+pub(crate) fn parse_entries<'c>(content: &'c str, lines: &[&'c str]) -> Vec<Entry<'c>> {
+    let mut entries = Vec::new();
+    let mut block: Option<(&str, &str)> = None;
+    let mut after_blank = false;
+
+    for &line in lines {
+        if line.trim().is_empty() {
+            after_blank = true;
+            continue;
+        }
+        let starts_block = is_list_item(line)
+            || line.starts_with('#')
+            || (after_blank && !line.starts_with(char::is_whitespace));
+        match block.as_mut() {
+            Some((_, last)) if !starts_block => *last = line,
+            _ => {
+                if let Some((first, last)) = block.take() {
+                    entries.push(Entry::borrowed(span(content, first, last)));
+                }
+                block = Some((line, line));
+            }
+        }
+        after_blank = false;
+    }
+    if let Some((first, last)) = block {
+        entries.push(Entry::borrowed(span(content, first, last)));
+    }
+
+    entries
+}
+
+/// Write entries one after another.
+///
+/// Consecutive list items form a single list, anything else is separated by
+/// a blank line.
+pub(crate) fn fmt_entries(f: &mut fmt::Formatter<'_>, entries: &[Entry]) -> fmt::Result {
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            match entries[i - 1].is_list_item() && entry.is_list_item() {
+                true => f.write_str("\n")?,
+                false => f.write_str("\n\n")?,
+            }
+        }
+        fmt::Display::fmt(entry, f)?;
+    }
+    Ok(())
+}
+
+fn is_list_item(line: &str) -> bool {
+    line.starts_with("- ") || line.starts_with("* ") || line.starts_with("+ ")
+}
+
+fn indent_len(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Vec<Entry<'_>> {
+        let lines: Vec<&str> = content.lines().collect();
+        parse_entries(content, &lines)
+    }
+
+    #[test]
+    fn continuation_lines() {
+        let content = "- Lib is now based on parsing, not just\n  for updating.\n- Next.";
+        let entries = parse(content);
+        assert_eq!(2, entries.len());
+        assert_eq!(
+            "Lib is now based on parsing, not just\n  for updating.",
+            entries[0].text()
+        );
+        assert_eq!("Next.", entries[1].text());
+    }
+
+    #[test]
+    fn lazy_continuation_lines() {
+        let entries = parse("- Wraps\nwithout indent.\n- Next.");
+        assert_eq!("Wraps\nwithout indent.", entries[0].text());
+    }
+
+    #[test]
+    fn nested_items() {
+        let content = "- Parent\n  text.\n  - Child one\n    wraps.\n  - Child two\n- Next.";
+        let entries = parse(content);
+        assert_eq!(2, entries.len());
+
+        let nested = entries[0].nested();
+        let texts: Vec<_> = nested.iter().map(Entry::text).collect();
+        assert_eq!(vec!["Child one\n    wraps.", "Child two"], texts);
+        assert!(entries[1].nested().is_empty());
+    }
+
+    #[test]
+    fn source_is_borrowed() {
+        let content = "- First.\n- Second.";
+        let entries = parse(content);
+        assert!(matches!(entries[1].source, Cow::Borrowed("- Second.")));
+    }
+
+    #[test]
+    fn new_indents_continuation_lines() {
+        let entry = Entry::new("Wraps\nonto the next line.");
+        assert_eq!("- Wraps\n  onto the next line.", entry.source());
+        assert_eq!("Wraps\n  onto the next line.", entry.text());
+        // text from a parsed entry keeps its indentation
+        assert_eq!(entry, Entry::new(entry.text().to_owned()));
+    }
+
+    #[test]
+    fn paragraph_entries() {
+        let entries = parse("Some notes\nover two lines.\n\n- Entry.");
+        assert!(!entries[0].is_list_item());
+        assert_eq!("Some notes\nover two lines.", entries[0].text());
+        assert!(entries[1].is_list_item());
+    }
+}
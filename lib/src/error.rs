@@ -1,10 +1,15 @@
 use std::{borrow::Cow, fmt};
 
-use crate::InvalidVersion;
+use crate::{
+    BumpError, InvalidDate, InvalidScheme, InvalidVersion, ParseError, ReleaseError, UnknownVersion,
+};
 
 impl fmt::Display for InvalidVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "invalid version: `{}`", self.0)
+        match self {
+            Self::NotAVersion(v) => write!(f, "invalid version: `{}`", v),
+            Self::TooLarge(v) => write!(f, "invalid version: `{}`, number too large", v),
+        }
     }
 }
 
@@ -12,18 +17,122 @@ impl std::error::Error for InvalidVersion {}
 
 impl From<&str> for InvalidVersion {
     fn from(v: &str) -> Self {
-        InvalidVersion(v.into())
+        InvalidVersion::NotAVersion(v.into())
     }
 }
 
 impl From<String> for InvalidVersion {
     fn from(v: String) -> Self {
-        InvalidVersion(v)
+        InvalidVersion::NotAVersion(v)
     }
 }
 
 impl From<Cow<'_, str>> for InvalidVersion {
     fn from(v: Cow<'_, str>) -> Self {
-        InvalidVersion(v.into())
+        InvalidVersion::NotAVersion(v.into())
     }
 }
+
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date: `{}`, expected YYYY-MM-DD", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDate {}
+
+impl From<&str> for InvalidDate {
+    fn from(v: &str) -> Self {
+        InvalidDate(v.into())
+    }
+}
+
+impl From<String> for InvalidDate {
+    fn from(v: String) -> Self {
+        InvalidDate(v)
+    }
+}
+
+impl fmt::Display for InvalidScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid version scheme: `{}`, expected semver, YYYY.MM.DD, YY.MM.MICRO or numeric",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidScheme {}
+
+impl From<&str> for InvalidScheme {
+    fn from(v: &str) -> Self {
+        InvalidScheme(v.into())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateUnreleased(line) => {
+                write!(f, "line {}: duplicate `Unreleased` section", line)
+            }
+            Self::MalformedHeading(line) => write!(f, "line {}: malformed release heading", line),
+            Self::MissingTitle(line) => write!(f, "line {}: release heading has no title", line),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for BumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotGreater { current, next } => write!(
+                f,
+                "version `{}` is not greater than current version `{}`",
+                next, current
+            ),
+            Self::Overflow(current) => write!(f, "version number overflow bumping `{}`", current),
+            Self::Unresolved => write!(f, "`auto` version must be resolved before bumping"),
+            Self::NotPreRelease(current) => write!(f, "version `{}` is not a pre-release", current),
+            Self::InvalidPreid(preid) => {
+                write!(f, "invalid pre-release identifier: `{}`", preid)
+            }
+            Self::NoPreReleases(scheme) => {
+                write!(f, "the {} version scheme has no pre-releases", scheme)
+            }
+            Self::NotInScheme { version, scheme } => {
+                write!(f, "version `{}` is not a {} version", version, scheme)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BumpError {}
+
+impl fmt::Display for ReleaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoChanges => write!(f, "no unreleased changes to release"),
+            Self::VersionExists(version) => write!(f, "version `{}` already released", version),
+            Self::Bump(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReleaseError {}
+
+impl From<BumpError> for ReleaseError {
+    fn from(err: BumpError) -> Self {
+        Self::Bump(err)
+    }
+}
+
+impl fmt::Display for UnknownVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "version `{}` not found", self.0)
+    }
+}
+
+impl std::error::Error for UnknownVersion {}
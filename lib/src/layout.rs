@@ -0,0 +1,91 @@
+//! Where link reference definitions and line breaks were in a parsed
+//! document, so that it is written back as it was found.
+//!
+//! Release link definitions are kept as their lines of text, with the url
+//! replaced when the release url changes. Definitions without a place, such
+//! as the link of a new release, are written next to the link of a
+//! neighbouring release.
+
+use std::borrow::Cow;
+
+use crate::ReleaseTitle;
+
+/// Layout of the parsed input.
+///
+/// The layout is not part of the changelog's value, so it is ignored when
+/// comparing changelogs.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Layout<'c> {
+    pub(crate) line_ending: LineEnding,
+    /// Link reference definitions, in the order they were found.
+    pub(crate) links: Vec<LinkLine<'c>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+/// A link reference definition after the text of a part of the document.
+#[derive(Debug, Clone)]
+pub(crate) struct LinkLine<'c> {
+    pub(crate) place: LinkPlace<'c>,
+    /// Empty lines between the definition and the line before it.
+    pub(crate) blank_lines: usize,
+    pub(crate) link: PlacedLink<'c>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LinkPlace<'c> {
+    /// After the document header.
+    Header,
+    /// After the release with this title.
+    After(ReleaseTitle<'c>),
+    /// At the end of the document, after the last release.
+    End,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum PlacedLink<'c> {
+    /// Definition of the url of the release with this title, as written.
+    Release(ReleaseTitle<'c>, Cow<'c, str>),
+    /// The misc link at this index.
+    Misc(usize),
+}
+
+impl<'c> PartialEq for Layout<'c> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<'c> Eq for Layout<'c> {}
+
+impl LineEnding {
+    /// Line ending of the first line of `input`.
+    pub(crate) fn detect(input: &str) -> Self {
+        match input.find('\n') {
+            Some(index) if input[..index].ends_with('\r') => Self::CrLf,
+            _ => Self::Lf,
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+impl<'c> LinkLine<'c> {
+    /// Title of the release the definition is the url of.
+    pub(crate) fn release_title(&self) -> Option<&ReleaseTitle<'c>> {
+        match &self.link {
+            PlacedLink::Release(title, _) => Some(title),
+            PlacedLink::Misc(_) => None,
+        }
+    }
+}
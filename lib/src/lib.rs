@@ -1,34 +1,170 @@
 use std::borrow::Cow;
 
-use release::ReleaseTitle;
+pub use release::ReleaseTitle;
 
+pub mod lint;
+
+mod breaking;
 mod changelog;
 mod changelog_build;
+mod changelog_fix;
+mod changelog_release;
+mod changelog_yank;
+mod changes;
+mod entry;
 mod error;
+mod layout;
+mod links;
 mod next_version;
+mod numeric_version;
+mod parse;
 mod release;
+mod release_date;
+mod render;
+mod repo_links;
 mod semver;
+mod tag_format;
 mod unreleased;
 mod util;
+mod version_scheme;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum NextVersion<'a> {
     Major,
     Minor,
     Patch,
-    Custom(SemanticVersion<'a>),
+    /// A version given in full, a SemVer version or dot separated numbers.
+    Custom(ReleaseTitle<'a>),
+    Auto,
+    /// Increase the major number and start a pre-release, with an optional
+    /// pre-release identifier such as `beta`.
+    PreMajor(Option<Cow<'a, str>>),
+    /// Increase the minor number and start a pre-release.
+    PreMinor(Option<Cow<'a, str>>),
+    /// Increase the patch number and start a pre-release.
+    PrePatch(Option<Cow<'a, str>>),
+    /// Increase the pre-release number, or start a pre-release patch.
+    PreRelease(Option<Cow<'a, str>>),
+    /// Promote a pre-release to its release version.
+    Release,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+/// How [`NextVersion`] keywords apply to the current version.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum BumpPolicy {
+    /// Keywords always bump the component they name.
+    #[default]
+    Standard,
+    /// Cargo's SemVer compatibility rules: before `1.0.0` a breaking change
+    /// bumps the minor number and a feature bumps the patch number.
+    Cargo,
+}
+
+/// A SemVer 2.0.0 version, such as `1.2.3-beta.1+exp.sha.5114f85`.
+///
+/// Build metadata is ignored when comparing versions, for equality and
+/// hashing as well as ordering. So `1.0.0+a == 1.0.0+b`, while both are
+/// displayed with their metadata.
+#[derive(Debug, Clone)]
 pub struct SemanticVersion<'v> {
-    major: u16,
-    minor: u16,
-    patch: u16,
-    label: Option<Cow<'v, str>>,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<Identifier<'v>>,
+    build: Option<Cow<'v, str>>,
+}
+
+/// A version of dot separated numbers, such as `1.2` or `1.2.3.4`.
+///
+/// Release titles of three numbers are [`SemanticVersion`]s, so this is used
+/// for versions of other lengths.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct NumericVersion {
+    parts: Vec<u64>,
+}
+
+/// How versions are ordered and bumped.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum VersionScheme {
+    /// SemVer 2.0.0 versions, such as `1.2.3-rc.1`.
+    #[default]
+    SemVer,
+    /// Calendar versions made from the release date.
+    CalVer(CalVerFormat),
+    /// Any number of dot separated numbers, such as `1.2.3.4`.
+    Numeric,
+}
+
+/// The parts of a calendar version, months and days are not zero padded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CalVerFormat {
+    /// `YYYY.MM.DD`, such as `2024.10.3`.
+    YearMonthDay,
+    /// `YY.MM.MICRO`, such as `24.10.1` for the second release of the month.
+    ShortYearMonthMicro,
+}
+
+/// A dot separated part of a pre-release label, such as `rc` or `1` in
+/// `1.0.0-rc.1`.
+///
+/// Digits with a leading zero, such as `01`, are not valid identifiers, so
+/// versions with them are rejected when parsing.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Identifier<'v> {
+    Numeric(u64),
+    AlphaNumeric(Cow<'v, str>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InvalidVersion {
+    /// The input is not a version.
+    NotAVersion(String),
+    /// A version number does not fit in a `u64`.
+    TooLarge(String),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidDate(String);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidScheme(String);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BumpError {
+    NotGreater {
+        current: String,
+        next: String,
+    },
+    Overflow(String),
+    Unresolved,
+    NotPreRelease(String),
+    InvalidPreid(String),
+    /// The version scheme has no pre-release versions.
+    NoPreReleases(String),
+    /// A custom version that is not a version of the scheme.
+    NotInScheme {
+        version: String,
+        scheme: String,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct InvalidVersion(String);
+pub enum ParseError {
+    DuplicateUnreleased(usize),
+    MalformedHeading(usize),
+    MissingTitle(usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ReleaseError {
+    NoChanges,
+    VersionExists(String),
+    Bump(BumpError),
+}
+
+/// No release with the version was found.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnknownVersion(String);
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Changelog<'c> {
@@ -36,6 +172,19 @@ pub struct Changelog<'c> {
     unreleased: Release<'c>,
     releases: Vec<Release<'c>>,
     misc_links: Vec<Cow<'c, str>>,
+    link_style: LinkStyle,
+    layout: layout::Layout<'c>,
+}
+
+/// How release urls are written.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LinkStyle {
+    /// Link reference definitions at the end of the document:
+    /// `[1.0.0]: https://...`
+    #[default]
+    Reference,
+    /// Links in the release headings: `## [1.0.0](https://...) - 2022-10-03`
+    Inline,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -44,6 +193,105 @@ pub struct Release<'c> {
     url: Option<Cow<'c, str>>,
     date: Option<Cow<'c, str>>,
     content: Option<Cow<'c, str>>,
+    yanked: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ChangeKind<'c> {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+    Security,
+    Custom(Cow<'c, str>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Changes<'c> {
+    intro: Vec<Entry<'c>>,
+    sections: Vec<Section<'c>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Section<'c> {
+    kind: ChangeKind<'c>,
+    heading: Cow<'c, str>,
+    entries: Vec<Entry<'c>>,
+    groups: Vec<Group<'c>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Group<'c> {
+    name: Cow<'c, str>,
+    entries: Vec<Entry<'c>>,
+}
+
+/// A calendar date in the `YYYY-MM-DD` format.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct ReleaseDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ReleaseOptions {
+    policy: BumpPolicy,
+    keep_links: bool,
+    repo_links: Option<RepoLinks>,
+    tag_format: Option<TagFormat>,
+    fold_pre_releases: Option<PreReleaseFold>,
+    scheme: VersionScheme,
+}
+
+/// What happens to pre-releases once their changes are folded into the
+/// release they lead up to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PreReleaseFold {
+    /// Remove the pre-releases.
+    Remove,
+    /// Keep the pre-release headings, without their changes.
+    Collapse,
+}
+
+/// How git tags are named after versions, such as `v1.2.3` or `chog@1.2.3`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct TagFormat {
+    prefix: String,
+    suffix: String,
+}
+
+/// Url formats of a source code forge.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+    /// Gitea and Forgejo, including Codeberg.
+    Gitea,
+    Bitbucket,
+    /// User provided url templates.
+    Custom,
+}
+
+/// Generates compare, tag and issue urls for a repository.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RepoLinks {
+    repository: String,
+    forge: ForgeKind,
+    compare: String,
+    tag: String,
+    issue: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Entry<'c> {
+    source: Cow<'c, str>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BreakingMarkers<'m> {
+    markers: Vec<Cow<'m, str>>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -0,0 +1,420 @@
+//! Link url maintenance for new releases.
+//!
+//! Urls are updated by example: the tag of the previous release is found in
+//! the existing links, and replaced with the tag of the new release in the
+//! same [`TagFormat`]. When the repository is known, urls are generated by
+//! its [`RepoLinks`] instead.
+
+use crate::{RepoLinks, TagFormat};
+
+/// A compare url, such as `https://github.com/user/repo/compare/v1.0.0...HEAD`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct CompareUrl<'u> {
+    prefix: &'u str,
+    from: &'u str,
+    separator: &'u str,
+    to: &'u str,
+    suffix: &'u str,
+}
+
+impl<'u> CompareUrl<'u> {
+    fn parse(url: &'u str) -> Option<Self> {
+        let (sep_start, separator) = match url.rfind("...") {
+            Some(index) => (index, "..."),
+            None => (url.rfind("..")?, ".."),
+        };
+        let before = &url[..sep_start];
+        let from_start = before.rfind('/')? + 1;
+        let after = &url[sep_start + separator.len()..];
+        let to_end = after.find(['?', '#', '/']).unwrap_or(after.len());
+        let (from, to) = (&before[from_start..], &after[..to_end]);
+        if from.is_empty() || to.is_empty() {
+            return None;
+        }
+        Some(Self {
+            prefix: &url[..from_start],
+            from,
+            separator,
+            to,
+            suffix: &after[to_end..],
+        })
+    }
+
+    /// The repository url before the compare path, such as
+    /// `https://github.com/user/repo`.
+    fn repository(&self) -> Option<&'u str> {
+        ["/-/compare/", "/compare/"]
+            .into_iter()
+            .find_map(|path| self.prefix.strip_suffix(path))
+    }
+
+    fn with_tags(&self, from: &str, to: &str) -> String {
+        format!(
+            "{}{}{}{}{}",
+            self.prefix, from, self.separator, to, self.suffix
+        )
+    }
+}
+
+/// Updated urls after releasing `version` following the `previous` version.
+///
+/// Returns the new unreleased url and the url for the new release. The new
+/// release url follows the style of the `previous_url`: either a compare url
+/// or a link to a single tag. When the previous release has no url, a compare
+/// url is derived from the unreleased url.
+///
+/// Tags are named after the `format` when given, otherwise after the tag of
+/// the previous release found in the urls. Existing tags in other formats
+/// are renamed to the given `format`.
+pub(crate) fn release_urls(
+    unreleased_url: Option<&str>,
+    previous_url: Option<&str>,
+    previous: &str,
+    version: &str,
+    format: Option<&TagFormat>,
+) -> (Option<String>, Option<String>) {
+    let unreleased = unreleased_url
+        .and_then(CompareUrl::parse)
+        .filter(|url| url.to.eq_ignore_ascii_case("HEAD"));
+    let previous_compare = previous_url.and_then(CompareUrl::parse);
+
+    let previous_tag = match (unreleased, previous_compare, previous_url) {
+        (Some(url), _, _) => url.from,
+        (None, Some(url), _) => url.to,
+        (None, None, Some(url)) => url.rsplit('/').next().unwrap_or_default(),
+        (None, None, None) => return (None, None),
+    };
+    let found = match TagFormat::from_tag(previous_tag, previous) {
+        Some(found) => found,
+        None => return (None, None),
+    };
+    let tag = format.unwrap_or(&found).tag(&version);
+    let base = |existing: &str| match format {
+        Some(format) if !format.matches(existing) => format.tag(&previous),
+        _ => existing.to_owned(),
+    };
+
+    let new_unreleased = unreleased.map(|url| url.with_tags(&tag, url.to));
+    let new_release = match (previous_compare, previous_url, unreleased) {
+        (Some(url), _, _) => Some(url.with_tags(&base(url.to), &tag)),
+        (None, Some(url), _) => replace_last(url, previous_tag, &tag),
+        (None, None, Some(url)) => Some(url.with_tags(&base(url.from), &tag)),
+        (None, None, None) => None,
+    };
+
+    (new_unreleased, new_release)
+}
+
+/// The unreleased compare url changed to compare from the `base` version
+/// instead of the `from` version, such as when the pre-releases it compared
+/// from are folded into the new release.
+///
+/// The base tag is named after the `format` when given, otherwise in the
+/// format of the existing tag.
+pub(crate) fn rebase_unreleased_url(
+    url: &str,
+    from: &str,
+    base: &str,
+    format: Option<&TagFormat>,
+) -> Option<String> {
+    let url = CompareUrl::parse(url)?;
+    let found = TagFormat::from_tag(url.from, from)?;
+    let tag = format.unwrap_or(&found).tag(&base);
+    Some(url.with_tags(&tag, url.to))
+}
+
+/// Urls generated from `links` after releasing the `tag`.
+///
+/// The new release compares from the `previous` tag, unless the previous
+/// release links to its tag alone, in the format of the tag url template. The
+/// first release always links to its tag.
+pub(crate) fn repo_release_urls(
+    links: &RepoLinks,
+    previous_url: Option<&str>,
+    previous: Option<&str>,
+    tag: &str,
+) -> (String, String) {
+    let tag_style =
+        previous_url.is_some_and(|url| links.is_tag_url(url) && !links.is_compare_url(url));
+    let release_url = match previous {
+        Some(previous) if !tag_style => links.compare_url(previous, tag),
+        _ => links.tag_url(tag),
+    };
+    (links.unreleased_url(tag), release_url)
+}
+
+/// Url for the release of `version` after the `previous` version, in the style
+/// of the `example` url that includes the `example_version` tag.
+///
+/// A `version` of `None` is the unreleased changes, compared to `HEAD`.
+/// Without a `previous` version, compare urls fall back to the url of the
+/// version's tag in the repository.
+pub(crate) fn derive_url(
+    example: &str,
+    example_version: &str,
+    previous: Option<&str>,
+    version: Option<&str>,
+) -> Option<String> {
+    let Some(compare) = CompareUrl::parse(example) else {
+        // a link to a single tag
+        return replace_last(example, example_version, version?);
+    };
+    let format = [compare.to, compare.from]
+        .into_iter()
+        .find_map(|tag| TagFormat::from_tag(tag, example_version))?;
+    let to = match version {
+        Some(version) => format.tag(&version),
+        None => "HEAD".to_string(),
+    };
+    match previous {
+        Some(previous) => Some(compare.with_tags(&format.tag(&previous), &to)),
+        // the first release has nothing to compare to
+        None => Some(RepoLinks::new(compare.repository()?).tag_url(&format.tag(&version?))),
+    }
+}
+
+/// Replace the last occurrence of `from` in `text`.
+fn replace_last(text: &str, from: &str, to: &str) -> Option<String> {
+    let index = text.rfind(from)?;
+    Some(format!(
+        "{}{}{}",
+        &text[..index],
+        to,
+        &text[index + from.len()..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "https://github.com/user/repo";
+
+    fn check(
+        expected: (Option<&str>, Option<&str>),
+        unreleased: Option<&str>,
+        previous_url: Option<&str>,
+    ) {
+        let expected = (
+            expected.0.map(|url| format!("{}{}", BASE, url)),
+            expected.1.map(|url| format!("{}{}", BASE, url)),
+        );
+        let unreleased = unreleased.map(|url| format!("{}{}", BASE, url));
+        let previous_url = previous_url.map(|url| format!("{}{}", BASE, url));
+        let actual = release_urls(
+            unreleased.as_deref(),
+            previous_url.as_deref(),
+            "1.0.0",
+            "1.1.0",
+            None,
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tag_style() {
+        check(
+            (Some("/compare/v1.1.0...HEAD"), Some("/releases/tag/v1.1.0")),
+            Some("/compare/v1.0.0...HEAD"),
+            Some("/releases/tag/v1.0.0"),
+        );
+    }
+
+    #[test]
+    fn compare_style() {
+        check(
+            (
+                Some("/compare/v1.1.0...HEAD"),
+                Some("/compare/v1.0.0...v1.1.0"),
+            ),
+            Some("/compare/v1.0.0...HEAD"),
+            Some("/compare/v0.9.0...v1.0.0"),
+        );
+    }
+
+    #[test]
+    fn no_previous_url_uses_compare_style() {
+        check(
+            (
+                Some("/compare/v1.1.0...HEAD"),
+                Some("/compare/v1.0.0...v1.1.0"),
+            ),
+            Some("/compare/v1.0.0...HEAD"),
+            None,
+        );
+    }
+
+    #[test]
+    fn no_unreleased_url() {
+        check(
+            (None, Some("/releases/tag/v1.1.0")),
+            None,
+            Some("/releases/tag/v1.0.0"),
+        );
+        check(
+            (None, Some("/compare/v1.0.0...v1.1.0")),
+            None,
+            Some("/compare/v0.9.0...v1.0.0"),
+        );
+    }
+
+    #[test]
+    fn tag_prefix_kept() {
+        check(
+            (
+                Some("/compare/chog-cli-v1.1.0...HEAD"),
+                Some("/releases/tag/chog-cli-v1.1.0"),
+            ),
+            Some("/compare/chog-cli-v1.0.0...HEAD"),
+            Some("/releases/tag/chog-cli-v1.0.0"),
+        );
+    }
+
+    #[test]
+    fn tag_format_names_new_tags() {
+        let unreleased = format!("{}/compare/v1.0.0...HEAD", BASE);
+        let format = TagFormat::package("chog-cli");
+        let cases = [
+            (
+                format!("{}/compare/v0.9.0...v1.0.0", BASE),
+                "/compare/chog-cli-v1.0.0...chog-cli-v1.1.0",
+            ),
+            (
+                format!("{}/releases/tag/v1.0.0", BASE),
+                "/releases/tag/chog-cli-v1.1.0",
+            ),
+            (
+                format!("{}/compare/chog-cli-v0.9.0...chog-cli-v1.0.0", BASE),
+                "/compare/chog-cli-v1.0.0...chog-cli-v1.1.0",
+            ),
+        ];
+        for (previous_url, expected) in cases {
+            let actual = release_urls(
+                Some(&unreleased),
+                Some(&previous_url),
+                "1.0.0",
+                "1.1.0",
+                Some(&format),
+            );
+            let expected = (
+                Some(format!("{}/compare/chog-cli-v1.1.0...HEAD", BASE)),
+                Some(format!("{}{}", BASE, expected)),
+            );
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn rebase_unreleased() {
+        let url = format!("{}/compare/v1.0.0-rc.1...HEAD", BASE);
+        assert_eq!(
+            Some(format!("{}/compare/v0.2.0...HEAD", BASE)),
+            rebase_unreleased_url(&url, "1.0.0-rc.1", "0.2.0", None)
+        );
+        assert_eq!(None, rebase_unreleased_url(&url, "0.9.0", "0.2.0", None));
+        let tag = format!("{}/releases/tag/v1.0.0-rc.1", BASE);
+        assert_eq!(
+            None,
+            rebase_unreleased_url(&tag, "1.0.0-rc.1", "0.2.0", None)
+        );
+        let format = TagFormat::package("chog");
+        assert_eq!(
+            Some(format!("{}/compare/chog-v0.2.0...HEAD", BASE)),
+            rebase_unreleased_url(&url, "1.0.0-rc.1", "0.2.0", Some(&format))
+        );
+    }
+
+    #[test]
+    fn gitlab_compare_with_two_dots() {
+        check(
+            (
+                Some("/-/compare/1.1.0..HEAD"),
+                Some("/-/compare/1.0.0..1.1.0"),
+            ),
+            Some("/-/compare/1.0.0..HEAD"),
+            None,
+        );
+    }
+
+    #[test]
+    fn repo_links_follow_previous_style() {
+        let links = RepoLinks::new("https://gitlab.com/group/sub/repo");
+        let tag_url = "https://gitlab.com/group/sub/repo/-/tags/v1.0.0";
+        let compare_url = "https://gitlab.com/group/sub/repo/-/compare/v0.9.0...v1.0.0";
+        let cases = [
+            (None, "/-/compare/v1.0.0...v1.1.0"),
+            (Some(compare_url), "/-/compare/v1.0.0...v1.1.0"),
+            (Some(tag_url), "/-/tags/v1.1.0"),
+        ];
+        for (previous_url, expected) in cases {
+            let (unreleased, release) =
+                repo_release_urls(&links, previous_url, Some("v1.0.0"), "v1.1.0");
+            assert_eq!(
+                format!("{}/-/compare/v1.1.0...HEAD", links.repository()),
+                unreleased
+            );
+            assert_eq!(format!("{}{}", links.repository(), expected), release);
+        }
+    }
+
+    #[test]
+    fn repo_links_first_release() {
+        let links = RepoLinks::new(BASE);
+        let (unreleased, release) = repo_release_urls(&links, None, None, "v0.1.0");
+        assert_eq!(format!("{}/compare/v0.1.0...HEAD", BASE), unreleased);
+        assert_eq!(format!("{}/releases/tag/v0.1.0", BASE), release);
+    }
+
+    #[test]
+    fn derive_from_example() {
+        let compare = format!("{}/compare/v0.9.0...v1.0.0", BASE);
+        let tag = format!("{}/releases/tag/v1.0.0", BASE);
+        let unreleased = format!("{}/compare/v1.0.0...HEAD", BASE);
+        let cases = [
+            (
+                &compare,
+                Some("0.1.0"),
+                Some("0.2.0"),
+                Some("/compare/v0.1.0...v0.2.0"),
+            ),
+            (
+                &compare,
+                Some("1.0.0"),
+                None,
+                Some("/compare/v1.0.0...HEAD"),
+            ),
+            (&compare, None, Some("0.1.0"), Some("/releases/tag/v0.1.0")),
+            (&compare, None, None, None),
+            (&tag, None, Some("0.1.0"), Some("/releases/tag/v0.1.0")),
+            (&tag, Some("1.0.0"), None, None),
+            (
+                &unreleased,
+                Some("0.1.0"),
+                Some("0.2.0"),
+                Some("/compare/v0.1.0...v0.2.0"),
+            ),
+        ];
+        for (example, previous, version, expected) in cases {
+            let expected = expected.map(|url| format!("{}{}", BASE, url));
+            assert_eq!(
+                expected,
+                derive_url(example, "1.0.0", previous, version),
+                "{:?} after {:?}",
+                version,
+                previous
+            );
+        }
+        let gitlab = "https://gitlab.com/group/project/-/compare/v0.9.0...v1.0.0";
+        assert_eq!(
+            Some("https://gitlab.com/group/project/-/tags/v0.1.0".to_string()),
+            derive_url(gitlab, "1.0.0", None, Some("0.1.0"))
+        );
+    }
+
+    #[test]
+    fn unknown_tags_unchanged() {
+        check((None, None), Some("/compare/main...HEAD"), None);
+        check((None, None), Some("/compare/v11.0.0...HEAD"), None);
+        check((None, None), None, None);
+    }
+}
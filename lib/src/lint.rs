@@ -0,0 +1,561 @@
+//! Changelog checks, such as for gating pull requests.
+//!
+//! [`check`] reports a [`Diagnostic`] for each problem found by the
+//! [`Rule`]s enabled in a [`Config`].
+
+use std::fmt;
+
+use crate::{
+    parse::{parse_link_definition, Spans},
+    util::offset,
+    BreakingMarkers, Changelog, ParseError, Release, ReleaseDate, ReleaseTitle,
+};
+
+/// A problem found in a changelog.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    code: Rule,
+    severity: Severity,
+    span: Span,
+    message: String,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// The line a diagnostic is about, as a byte range of the input without the
+/// line ending.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Span {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Rule {
+    /// A release is listed below an older release.
+    ReleaseOrder,
+    /// A release title is used more than once.
+    DuplicateVersion,
+    /// A release is dated after the release listed above it.
+    DateOrder,
+    /// A release heading refers to a link that is not defined.
+    MissingLink,
+    /// A link definition is not used.
+    DanglingLink,
+    /// A section heading is not a Keep a Changelog change kind, or is not
+    /// spelled like one.
+    SectionHeading,
+    /// A section has no entries.
+    EmptySection,
+    /// There is no `Unreleased` section.
+    MissingUnreleased,
+    /// A release is dated more than a day after today, which allows for
+    /// time zones ahead of UTC.
+    FutureDate,
+}
+
+/// Which rules are checked, and the severity of their diagnostics.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Config {
+    severities: [Option<Severity>; Rule::ALL.len()],
+    today: Option<ReleaseDate>,
+}
+
+/// Check the changelog `input` with the rules of `config`.
+///
+/// Diagnostics are returned in the order of the lines they are about. An
+/// input that cannot be parsed as a changelog is an error.
+pub fn check(input: &str, config: &Config) -> Result<Vec<Diagnostic>, ParseError> {
+    let mut checker = Checker::new(input, config)?;
+    checker.check_unreleased();
+    checker.check_releases();
+    checker.check_links();
+    checker.check_sections();
+
+    let mut diagnostics = checker.diagnostics;
+    diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+    Ok(diagnostics)
+}
+
+impl Diagnostic {
+    pub fn code(&self) -> Rule {
+        self.code
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Span {
+    /// Line number, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+impl Rule {
+    pub const ALL: [Rule; 9] = [
+        Rule::ReleaseOrder,
+        Rule::DuplicateVersion,
+        Rule::DateOrder,
+        Rule::MissingLink,
+        Rule::DanglingLink,
+        Rule::SectionHeading,
+        Rule::EmptySection,
+        Rule::MissingUnreleased,
+        Rule::FutureDate,
+    ];
+
+    /// Name of the rule in diagnostics, such as `release-order`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ReleaseOrder => "release-order",
+            Self::DuplicateVersion => "duplicate-version",
+            Self::DateOrder => "date-order",
+            Self::MissingLink => "missing-link",
+            Self::DanglingLink => "dangling-link",
+            Self::SectionHeading => "section-heading",
+            Self::EmptySection => "empty-section",
+            Self::MissingUnreleased => "missing-unreleased",
+            Self::FutureDate => "future-date",
+        }
+    }
+
+    /// The rule with the `code`.
+    pub fn from_code(code: &str) -> Option<Rule> {
+        Self::ALL.into_iter().find(|rule| rule.code() == code)
+    }
+
+    /// Problems with the release history are errors, the rest are warnings.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            Self::ReleaseOrder | Self::DuplicateVersion | Self::DateOrder | Self::FutureDate => {
+                Severity::Error
+            }
+            _ => Severity::Warning,
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|rule| rule == self)
+            .expect("all rules listed")
+    }
+}
+
+impl Config {
+    /// Every rule at its default severity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn severity(mut self, rule: Rule, severity: Severity) -> Self {
+        self.severities[rule.index()] = Some(severity);
+        self
+    }
+
+    /// Do not check the `rule`.
+    pub fn allow(mut self, rule: Rule) -> Self {
+        self.severities[rule.index()] = None;
+        self
+    }
+
+    /// The date future release dates are compared to, today in UTC by
+    /// default.
+    pub fn today(mut self, date: ReleaseDate) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    /// Severity of the `rule`, or `None` when it is not checked.
+    pub fn severity_of(&self, rule: Rule) -> Option<Severity> {
+        self.severities[rule.index()]
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            severities: Rule::ALL.map(|rule| Some(rule.default_severity())),
+            today: None,
+        }
+    }
+}
+
+struct Checker<'c, 'o> {
+    config: &'o Config,
+    input: &'c str,
+    changelog: Changelog<'c>,
+    spans: Spans<'c>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'c, 'o> Checker<'c, 'o> {
+    fn new(input: &'c str, config: &'o Config) -> Result<Self, ParseError> {
+        let (changelog, spans) = Changelog::parse_with_spans(input)?;
+        Ok(Self {
+            config,
+            input,
+            changelog,
+            spans,
+            diagnostics: Vec::new(),
+        })
+    }
+
+    /// Releases with a heading, including `Unreleased`, and the line index of
+    /// their heading.
+    fn headings(&self) -> impl Iterator<Item = (usize, &Release<'c>)> {
+        let unreleased = self
+            .spans
+            .unreleased
+            .map(|index| (index, &self.changelog.unreleased));
+        let releases = self.spans.releases.iter().copied();
+        unreleased
+            .into_iter()
+            .chain(releases.zip(&self.changelog.releases))
+    }
+
+    /// Line index of text borrowed from the input.
+    fn line_of(&self, text: &str) -> usize {
+        self.spans.line_at(offset(self.input, text))
+    }
+
+    fn push(&mut self, rule: Rule, index: usize, message: String) {
+        let Some(severity) = self.config.severity_of(rule) else {
+            return;
+        };
+        let span = match self.spans.lines.get(index) {
+            Some(line) => Span {
+                line: index + 1,
+                start: line.start,
+                end: line.end,
+            },
+            None => Span {
+                line: 1,
+                start: 0,
+                end: 0,
+            },
+        };
+        self.diagnostics.push(Diagnostic {
+            code: rule,
+            severity,
+            span,
+            message,
+        });
+    }
+
+    fn check_unreleased(&mut self) {
+        if self.spans.unreleased.is_none() {
+            let index = self.spans.releases.first().copied().unwrap_or(0);
+            let message = "no `Unreleased` section".to_string();
+            self.push(Rule::MissingUnreleased, index, message);
+        }
+    }
+
+    fn check_releases(&mut self) {
+        // releases a day ahead are fine, as today is in UTC
+        let latest = self
+            .config
+            .today
+            .unwrap_or_else(ReleaseDate::today)
+            .next_day();
+        let mut found: Vec<(usize, &ReleaseTitle)> = Vec::new();
+        let mut above: Option<&ReleaseTitle> = None;
+        let mut above_date: Option<ReleaseDate> = None;
+        let mut diagnostics = Vec::new();
+
+        let releases = self.spans.releases.iter().zip(&self.changelog.releases);
+        for (index, release) in releases {
+            let title = &release.title;
+            let is_version = matches!(title, ReleaseTitle::SemVer(_) | ReleaseTitle::Numeric(_));
+            if let Some((first, _)) = found.iter().find(|(_, found)| *found == title) {
+                let message = format!(
+                    "release `{}` is already listed on line {}",
+                    title,
+                    first + 1
+                );
+                diagnostics.push((Rule::DuplicateVersion, *index, message));
+            } else if let Some(above) = above.filter(|above| is_version && title > *above) {
+                let message = format!(
+                    "release `{}` is listed below the older release `{}`",
+                    title, above
+                );
+                diagnostics.push((Rule::ReleaseOrder, *index, message));
+            }
+
+            if let Some(date) = release.release_date() {
+                if let Some(above_date) = above_date.filter(|above_date| date > *above_date) {
+                    let message = format!(
+                        "release `{}` is dated {}, after {} of the release above",
+                        title, date, above_date
+                    );
+                    diagnostics.push((Rule::DateOrder, *index, message));
+                }
+                if date > latest {
+                    let message = format!("release `{}` is dated {}, in the future", title, date);
+                    diagnostics.push((Rule::FutureDate, *index, message));
+                }
+                above_date = Some(date);
+            }
+
+            if is_version {
+                above = Some(title);
+            }
+            found.push((*index, title));
+        }
+
+        for (rule, index, message) in diagnostics {
+            self.push(rule, index, message);
+        }
+    }
+
+    fn check_links(&mut self) {
+        let mut diagnostics = Vec::new();
+        for (index, release) in self.headings() {
+            let text = self.spans.lines[index]
+                .text
+                .trim_start_matches('#')
+                .trim_start();
+            // link definitions were given to the releases when parsing
+            if text.starts_with('[') && release.url().is_none() {
+                let message = format!("no link definition for `[{}]`", release.title);
+                diagnostics.push((Rule::MissingLink, index, message));
+            }
+        }
+
+        for link in self.changelog.misc_links() {
+            let Some(definition) = parse_link_definition(link) else {
+                continue;
+            };
+            let label = format!("[{}]", definition.label.to_lowercase());
+            let used = self.spans.lines.iter().any(|line| {
+                parse_link_definition(line.text).is_none()
+                    && line.text.to_lowercase().contains(&label)
+            });
+            if !used {
+                let message = format!("link definition `[{}]` is not used", definition.label);
+                diagnostics.push((Rule::DanglingLink, self.line_of(link), message));
+            }
+        }
+
+        for (rule, index, message) in diagnostics {
+            self.push(rule, index, message);
+        }
+    }
+
+    fn check_sections(&mut self) {
+        let mut diagnostics = Vec::new();
+        for (_, release) in self.headings() {
+            for section in release.changes().sections() {
+                let index = self.line_of(section.heading());
+                let heading = section.heading();
+                let name = BreakingMarkers::default()
+                    .strip(heading)
+                    .unwrap_or(heading)
+                    .trim();
+                let kind = section.kind();
+                if kind.is_custom() {
+                    let message = format!("non-standard section heading `{}`", name);
+                    diagnostics.push((Rule::SectionHeading, index, message));
+                } else if name != kind.as_str() {
+                    let message = format!("section heading `{}` should be `{}`", name, kind);
+                    diagnostics.push((Rule::SectionHeading, index, message));
+                }
+                if section.is_empty() {
+                    let message = format!("section `{}` has no entries", heading);
+                    diagnostics.push((Rule::EmptySection, index, message));
+                }
+            }
+        }
+
+        for (rule, index, message) in diagnostics {
+            self.push(rule, index, message);
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {}[{}]: {}",
+            self.span.line, self.severity, self.code, self.message
+        )
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config::new().today(ReleaseDate::new(2022, 10, 20).expect("valid date"))
+    }
+
+    fn codes(input: &str, config: &Config) -> Vec<(usize, Rule)> {
+        check(input, config)
+            .expect("valid changelog")
+            .iter()
+            .map(|diagnostic| (diagnostic.span().line(), diagnostic.code()))
+            .collect()
+    }
+
+    const CLEAN: &str = "# Changelog\n\n\
+        ## [Unreleased]\n\n\
+        ### Added\n\n- Feature.\n\n\
+        ## [1.0.0] - 2022-10-03\n\n\
+        ### Fixed\n\n- Bug.\n\n\
+        ## [0.1.0] - 2022-09-01\n\n\
+        - First release.\n\n\
+        [Unreleased]: https://a.b/compare/v1.0.0...HEAD\n\
+        [1.0.0]: https://a.b/compare/v0.1.0...v1.0.0\n\
+        [0.1.0]: https://a.b/tag/v0.1.0\n";
+
+    #[test]
+    fn clean_changelog() {
+        assert_eq!(Vec::<(usize, Rule)>::new(), codes(CLEAN, &config()));
+    }
+
+    #[test]
+    fn release_history() {
+        let input = "## Unreleased\n\n\
+            ## 1.0.0 - 2022-09-01\n\n\
+            ## 1.1.0 - 2022-10-01\n\n\
+            ## 1.0.0 - 2022-08-01\n\n\
+            ## 0.1.0 - 2023-01-01\n";
+        let expected = vec![
+            (5, Rule::ReleaseOrder),
+            (5, Rule::DateOrder),
+            (7, Rule::DuplicateVersion),
+            (9, Rule::DateOrder),
+            (9, Rule::FutureDate),
+        ];
+        assert_eq!(expected, codes(input, &config()));
+    }
+
+    #[test]
+    fn future_date_allows_a_day() {
+        let input = "## Unreleased\n\n\
+            ## 1.1.0 - 2022-10-22\n\n\
+            ## 1.0.0 - 2022-10-21\n";
+        let expected = vec![(3, Rule::FutureDate)];
+        assert_eq!(expected, codes(input, &config()));
+    }
+
+    #[test]
+    fn links() {
+        let input = "## [Unreleased]\n\n\
+            ## [1.0.0] - 2022-10-03\n\n\
+            - See [docs].\n\n\
+            [docs]: https://a.b/docs\n\
+            [0.9.0]: https://a.b/tag/v0.9.0\n\
+            [Unreleased]: https://a.b/compare/v1.0.0...HEAD\n";
+        let expected = vec![(3, Rule::MissingLink), (8, Rule::DanglingLink)];
+        assert_eq!(expected, codes(input, &config()));
+    }
+
+    #[test]
+    fn sections() {
+        let input = "## Unreleased\n\n\
+            ### added\n\n- Feature.\n\n\
+            ### Documentation\n\n- Docs.\n\n\
+            ### Fixed\n\n\
+            ### [**BREAKING**] Removed\n\n```\ncode\n```\n\n\
+            ## 1.0.0 - 2022-10-03\n\n\
+            ### Security\n";
+        let expected = vec![
+            (3, Rule::SectionHeading),
+            (7, Rule::SectionHeading),
+            (11, Rule::EmptySection),
+            (21, Rule::EmptySection),
+        ];
+        assert_eq!(expected, codes(input, &config()));
+    }
+
+    #[test]
+    fn sections_found_like_the_parser() {
+        let input = "## Unreleased\n\n\
+            ### Added\n\n```md\n### not a section\n```\n\n\
+            ## 1.0.0 - 2022-10-03\n\n\
+            ### Fixed\n\n#### Lib\n";
+        let expected = vec![(11, Rule::EmptySection)];
+        assert_eq!(expected, codes(input, &config()));
+    }
+
+    #[test]
+    fn missing_unreleased() {
+        let input = "# Changelog\n\n## 1.0.0 - 2022-10-03\n\n- Change.\n";
+        assert_eq!(vec![(3, Rule::MissingUnreleased)], codes(input, &config()));
+    }
+
+    #[test]
+    fn configured_rules() {
+        let input = "## 1.0.0 - 2023-01-01\n\n### Docs\n\n- Docs.\n";
+        let config = config()
+            .allow(Rule::MissingUnreleased)
+            .allow(Rule::SectionHeading)
+            .severity(Rule::FutureDate, Severity::Warning);
+        let diagnostics = check(input, &config).expect("valid changelog");
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(
+            "line 1: warning[future-date]: release `1.0.0` is dated 2023-01-01, in the future",
+            diagnostics[0].to_string()
+        );
+        assert_eq!(
+            (0, 21),
+            (diagnostics[0].span().start(), diagnostics[0].span().end())
+        );
+    }
+
+    #[test]
+    fn rule_codes() {
+        for rule in Rule::ALL {
+            assert_eq!(Some(rule), Rule::from_code(rule.code()));
+        }
+        assert_eq!(None, Rule::from_code("unknown"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = check("## [1.0.0\n", &config()).expect_err("malformed heading");
+        assert_eq!(ParseError::MalformedHeading(1), err);
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{InvalidVersion, NextVersion};
+use std::borrow::Cow;
+
+use crate::{
+    BreakingMarkers, BumpPolicy, ChangeKind, Changelog, InvalidVersion, NextVersion, ReleaseTitle,
+    SemanticVersion,
+};
 
 impl<'a> TryFrom<&'a str> for NextVersion<'a> {
     type Error = InvalidVersion;
@@ -8,7 +13,13 @@ impl<'a> TryFrom<&'a str> for NextVersion<'a> {
             "major" => Ok(Self::Major),
             "minor" => Ok(Self::Minor),
             "patch" => Ok(Self::Patch),
-            custom => Ok(Self::Custom(custom.try_into()?)),
+            "auto" => Ok(Self::Auto),
+            "premajor" => Ok(Self::PreMajor(None)),
+            "preminor" => Ok(Self::PreMinor(None)),
+            "prepatch" => Ok(Self::PrePatch(None)),
+            "prerelease" => Ok(Self::PreRelease(None)),
+            "release" => Ok(Self::Release),
+            custom => Ok(Self::Custom(ReleaseTitle::version(custom)?)),
         }
     }
 }
@@ -17,11 +28,106 @@ impl<'a> NextVersion<'a> {
     pub fn new(version: &'a str) -> Result<Self, InvalidVersion> {
         version.try_into()
     }
+
+    /// Set the identifier of a pre-release keyword, such as `rc` to make
+    /// `1.0.0-rc.0`.
+    ///
+    /// Other versions are unchanged.
+    pub fn with_preid<T: Into<Cow<'a, str>>>(self, preid: T) -> Self {
+        let preid = Some(preid.into());
+        match self {
+            Self::PreMajor(_) => Self::PreMajor(preid),
+            Self::PreMinor(_) => Self::PreMinor(preid),
+            Self::PrePatch(_) => Self::PrePatch(preid),
+            Self::PreRelease(_) => Self::PreRelease(preid),
+            next => next,
+        }
+    }
+
+    pub fn preid(&self) -> Option<&str> {
+        match self {
+            Self::PreMajor(preid)
+            | Self::PreMinor(preid)
+            | Self::PrePatch(preid)
+            | Self::PreRelease(preid) => preid.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether the keyword makes a pre-release, so takes an identifier.
+    pub fn is_pre_release(&self) -> bool {
+        matches!(
+            self,
+            Self::PreMajor(_) | Self::PreMinor(_) | Self::PrePatch(_) | Self::PreRelease(_)
+        )
+    }
+
+    /// Apply the bump policy for the `current` version.
+    ///
+    /// With [`BumpPolicy::Cargo`] and a `0.x.y` version, `Major` becomes
+    /// `Minor` and `Minor` becomes `Patch`, and the same for their
+    /// pre-release keywords. Other versions and policies are unchanged.
+    pub fn resolve(self, current: &SemanticVersion, policy: BumpPolicy) -> Self {
+        match (policy, current.is_stable(), self) {
+            (BumpPolicy::Cargo, false, Self::Major) => Self::Minor,
+            (BumpPolicy::Cargo, false, Self::Minor) => Self::Patch,
+            (BumpPolicy::Cargo, false, Self::PreMajor(preid)) => Self::PreMinor(preid),
+            (BumpPolicy::Cargo, false, Self::PreMinor(preid)) => Self::PrePatch(preid),
+            (_, _, next) => next,
+        }
+    }
+}
+
+impl<'c> Changelog<'c> {
+    /// Next version inferred from the unreleased changes.
+    ///
+    /// - [`NextVersion::Major`] for any breaking changes.
+    /// - [`NextVersion::Minor`] for added, deprecated or removed entries.
+    /// - [`NextVersion::Patch`] otherwise, such as only fixed or security
+    ///   entries.
+    pub fn suggested_next_version(&self) -> NextVersion<'static> {
+        self.suggested_next_version_with(&BreakingMarkers::default())
+    }
+
+    pub fn suggested_next_version_with(&self, markers: &BreakingMarkers) -> NextVersion<'static> {
+        if self.unreleased.has_breaking_changes_with(markers) {
+            return NextVersion::Major;
+        }
+        let changes = self.unreleased.changes_with(markers);
+        let minor = changes.sections().iter().any(|section| {
+            !section.is_empty()
+                && matches!(
+                    section.kind(),
+                    ChangeKind::Added | ChangeKind::Deprecated | ChangeKind::Removed
+                )
+        });
+        match minor {
+            true => NextVersion::Minor,
+            false => NextVersion::Patch,
+        }
+    }
+
+    /// Replace [`NextVersion::Auto`] with the suggested next version, then
+    /// apply the bump `policy` for the latest release.
+    pub fn resolve_next_version<'a>(
+        &self,
+        next: NextVersion<'a>,
+        policy: BumpPolicy,
+    ) -> NextVersion<'a> {
+        let next = match next {
+            NextVersion::Auto => self.suggested_next_version(),
+            next => next,
+        };
+        match self.latest_version() {
+            Some(current) => next.resolve(current, policy),
+            None => next,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::SemanticVersion;
+    use crate::{ChangelogBuilder, NumericVersion, Release, SemanticVersion, Unreleased};
 
     use super::*;
 
@@ -48,7 +154,7 @@ mod tests {
 
     #[test]
     fn try_from_invalid_custom_versions() {
-        let invalids = ["1.a.2", "0.0", "0", "2.3.4.5", "2.3.4no"];
+        let invalids = ["1.a.2", "0", "2.3.4no", "1.2.3.4-rc.1", "release-1"];
         for v in invalids {
             let actual = match NextVersion::try_from(v) {
                 Ok(_) => panic!("input should be invalid: `{}`", v),
@@ -63,7 +169,11 @@ mod tests {
         let valids = ["0.0.0", "0.1.2", "1.50.3", "1.2.3-beta", "v1.2.3"];
         for input in valids {
             let semver = SemanticVersion::try_from(input).expect("valid version");
-            check_try_from(NextVersion::Custom(semver), input);
+            check_try_from(NextVersion::Custom(semver.into()), input);
+        }
+        for input in ["0.0", "2.3.4.5", "v1.2.3.4.5"] {
+            let numeric = NumericVersion::try_from(input).expect("valid version");
+            check_try_from(NextVersion::Custom(ReleaseTitle::Numeric(numeric)), input);
         }
     }
 
@@ -81,4 +191,130 @@ mod tests {
     fn try_from_patch() {
         check_try_from(NextVersion::Patch, "patch");
     }
+
+    #[test]
+    fn try_from_auto() {
+        check_try_from(NextVersion::Auto, "auto");
+    }
+
+    #[test]
+    fn try_from_pre_release_keywords() {
+        check_try_from(NextVersion::PreMajor(None), "premajor");
+        check_try_from(NextVersion::PreMinor(None), "preminor");
+        check_try_from(NextVersion::PrePatch(None), "prepatch");
+        check_try_from(NextVersion::PreRelease(None), "prerelease");
+        check_try_from(NextVersion::Release, "release");
+    }
+
+    #[test]
+    fn with_preid() {
+        let next = NextVersion::PreMinor(None).with_preid("beta");
+        assert_eq!(NextVersion::PreMinor(Some("beta".into())), next);
+        assert_eq!(Some("beta"), next.preid());
+        assert_eq!(NextVersion::Minor, NextVersion::Minor.with_preid("beta"));
+    }
+
+    fn check_suggested(expected: NextVersion, content: &str) {
+        let changelog = ChangelogBuilder::new()
+            .unreleased(Unreleased::with_content(content))
+            .build();
+        assert_eq!(
+            expected,
+            changelog.suggested_next_version(),
+            "content: {:?}",
+            content
+        );
+    }
+
+    #[test]
+    fn suggested_major_for_breaking_changes() {
+        check_suggested(
+            NextVersion::Major,
+            "### Fixed\n\n- [**BREAKING**] Bug that was relied on.",
+        );
+        check_suggested(
+            NextVersion::Major,
+            "### [**BREAKING**] Project changes\n\n- New layout.",
+        );
+    }
+
+    #[test]
+    fn suggested_minor_for_features() {
+        for kind in ["Added", "Deprecated", "Removed"] {
+            let content = format!("### Fixed\n\n- Bug.\n\n### {}\n\n- Entry.", kind);
+            check_suggested(NextVersion::Minor, &content);
+        }
+    }
+
+    #[test]
+    fn suggested_patch_for_fixes() {
+        check_suggested(NextVersion::Patch, "### Fixed\n\n- Bug.");
+        check_suggested(NextVersion::Patch, "### Security\n\n- Patch.");
+        check_suggested(NextVersion::Patch, "### Changed\n\n- Faster parsing.");
+        check_suggested(NextVersion::Patch, "### Documentation\n\n- Typos.");
+        check_suggested(NextVersion::Patch, "### Added\n\n### Fixed\n\n- Bug.");
+        check_suggested(NextVersion::Patch, "");
+    }
+
+    fn changelog_at<'c>(version: &'c str, content: &'c str) -> Changelog<'c> {
+        ChangelogBuilder::new()
+            .unreleased(Unreleased::with_content(content))
+            .add_release(Release::new(version, "2022-10-03"))
+            .build()
+    }
+
+    #[test]
+    fn resolve_auto() {
+        let changelog = changelog_at("1.0.0", "### Added\n\n- Feature.");
+        let resolve = |next| changelog.resolve_next_version(next, BumpPolicy::Standard);
+        assert_eq!(NextVersion::Minor, resolve(NextVersion::Auto));
+        assert_eq!(NextVersion::Major, resolve(NextVersion::Major));
+    }
+
+    #[test]
+    fn resolve_cargo_policy_pre_stable() {
+        let cases = [
+            (NextVersion::Minor, NextVersion::Major),
+            (NextVersion::Patch, NextVersion::Minor),
+            (NextVersion::Patch, NextVersion::Patch),
+            (
+                NextVersion::PreMinor(Some("rc".into())),
+                NextVersion::PreMajor(Some("rc".into())),
+            ),
+            (NextVersion::PrePatch(None), NextVersion::PreMinor(None)),
+            (NextVersion::PreRelease(None), NextVersion::PreRelease(None)),
+        ];
+        let current = SemanticVersion::new(0, 2, 0);
+        for (expected, next) in cases {
+            assert_eq!(expected, next.resolve(&current, BumpPolicy::Cargo));
+        }
+    }
+
+    #[test]
+    fn resolve_cargo_policy_stable() {
+        let current = SemanticVersion::new(1, 2, 0);
+        for next in [NextVersion::Major, NextVersion::Minor, NextVersion::Patch] {
+            assert_eq!(next.clone(), next.resolve(&current, BumpPolicy::Cargo));
+        }
+    }
+
+    #[test]
+    fn resolve_standard_policy_pre_stable() {
+        let current = SemanticVersion::new(0, 2, 0);
+        for next in [NextVersion::Major, NextVersion::Minor, NextVersion::Patch] {
+            assert_eq!(next.clone(), next.resolve(&current, BumpPolicy::Standard));
+        }
+    }
+
+    #[test]
+    fn resolve_auto_with_cargo_policy() {
+        let breaking = "### Changed\n\n- [**BREAKING**] New API.";
+        let resolve = |changelog: Changelog| {
+            changelog.resolve_next_version(NextVersion::Auto, BumpPolicy::Cargo)
+        };
+        assert_eq!(NextVersion::Minor, resolve(changelog_at("0.2.0", breaking)));
+        assert_eq!(NextVersion::Major, resolve(changelog_at("1.2.0", breaking)));
+        let feature = "### Added\n\n- Feature.";
+        assert_eq!(NextVersion::Patch, resolve(changelog_at("0.2.0", feature)));
+    }
 }
@@ -0,0 +1,231 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::{
+    BumpError, InvalidVersion, NextVersion, NumericVersion, ReleaseTitle, SemanticVersion,
+    VersionScheme,
+};
+
+impl NumericVersion {
+    /// A version of the `parts` numbers, no numbers is the version `0`.
+    pub fn new(parts: impl Into<Vec<u64>>) -> Self {
+        let mut parts = parts.into();
+        if parts.is_empty() {
+            parts.push(0);
+        }
+        Self { parts }
+    }
+
+    pub fn parts(&self) -> &[u64] {
+        &self.parts
+    }
+
+    /// Apply the `next` version keyword.
+    ///
+    /// `major` bumps the first number, `minor` the second and `patch` the
+    /// last, resetting the numbers after it to zero. Custom versions keep
+    /// their major, minor and patch numbers.
+    pub fn bump(&self, next: &NextVersion) -> Result<NumericVersion, BumpError> {
+        let index = match next {
+            NextVersion::Major => 0,
+            NextVersion::Minor => 1.min(self.parts.len() - 1),
+            NextVersion::Patch => self.parts.len() - 1,
+            NextVersion::Custom(ReleaseTitle::SemVer(custom)) if !custom.is_pre_release() => {
+                return self.ensure_greater(Self::from(custom))
+            }
+            NextVersion::Custom(ReleaseTitle::Numeric(custom)) => {
+                return self.ensure_greater(custom.clone())
+            }
+            NextVersion::Custom(ReleaseTitle::Title(custom)) => {
+                return Err(BumpError::NotInScheme {
+                    version: custom.to_string(),
+                    scheme: VersionScheme::Numeric.to_string(),
+                })
+            }
+            NextVersion::Auto => return Err(BumpError::Unresolved),
+            _ => return Err(BumpError::NoPreReleases(VersionScheme::Numeric.to_string())),
+        };
+
+        let mut parts = self.parts.clone();
+        parts[index] = parts[index]
+            .checked_add(1)
+            .ok_or_else(|| BumpError::Overflow(self.to_string()))?;
+        parts[index + 1..].fill(0);
+        Ok(Self { parts })
+    }
+
+    fn ensure_greater(&self, next: NumericVersion) -> Result<NumericVersion, BumpError> {
+        match &next > self {
+            true => Ok(next),
+            false => Err(BumpError::NotGreater {
+                current: self.to_string(),
+                next: next.to_string(),
+            }),
+        }
+    }
+}
+
+/// Compare numbers one by one, missing numbers count as zero. Equal numbers
+/// are ordered by length, so `1.0 < 1.0.0`.
+pub(crate) fn cmp_numbers(left: &[u64], right: &[u64]) -> Ordering {
+    let len = left.len().max(right.len());
+    let padded = |parts: &[u64], i: usize| parts.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| padded(left, i).cmp(&padded(right, i)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| left.len().cmp(&right.len()))
+}
+
+impl PartialOrd for NumericVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NumericVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_numbers(&self.parts, &other.parts)
+    }
+}
+
+/// The major, minor and patch numbers, ignoring any pre-release.
+impl From<&SemanticVersion<'_>> for NumericVersion {
+    fn from(version: &SemanticVersion) -> Self {
+        Self::new([version.major(), version.minor(), version.patch()])
+    }
+}
+
+impl TryFrom<&str> for NumericVersion {
+    type Error = InvalidVersion;
+
+    /// Parse two or more dot separated numbers, with an optional `v` prefix.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let version = input.strip_prefix('v').unwrap_or(input);
+        let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !version.contains('.') || !version.split('.').all(is_number) {
+            return Err(InvalidVersion::from(input));
+        }
+        let parts = version
+            .split('.')
+            .map(|part| {
+                part.parse()
+                    .map_err(|_| InvalidVersion::TooLarge(input.into()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { parts })
+    }
+}
+
+impl FromStr for NumericVersion {
+    type Err = InvalidVersion;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::try_from(input)
+    }
+}
+
+impl fmt::Display for NumericVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, part) in self.parts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            part.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(input: &str) -> NumericVersion {
+        NumericVersion::try_from(input).expect("valid version")
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(vec![1, 2], version("1.2").parts());
+        assert_eq!(vec![0, 0, 0, 1], version("v0.0.0.1").parts());
+        for invalid in ["1", "", "1.", ".1", "1..2", "1.2-rc", "a.b"] {
+            assert_eq!(
+                Err(InvalidVersion::from(invalid)),
+                NumericVersion::try_from(invalid)
+            );
+        }
+        let too_large = "1.18446744073709551616";
+        assert_eq!(
+            Err(InvalidVersion::TooLarge(too_large.into())),
+            NumericVersion::try_from(too_large)
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let low_to_high = ["0.0.0.1", "0.1", "1.0", "1.0.0.0", "1.0.0.1", "1.2", "10.0"];
+        for w in low_to_high.windows(2) {
+            assert!(version(w[0]) < version(w[1]), "`{}` < `{}`", w[0], w[1]);
+        }
+    }
+
+    #[test]
+    fn bump() {
+        let cases = [
+            ("2.0.0.0", NextVersion::Major),
+            ("1.3.0.0", NextVersion::Minor),
+            ("1.2.3.5", NextVersion::Patch),
+            (
+                "1.3.0",
+                NextVersion::Custom(SemanticVersion::new(1, 3, 0).into()),
+            ),
+            (
+                "1.2.3.5.1",
+                NextVersion::Custom(version("1.2.3.5.1").into()),
+            ),
+        ];
+        for (expected, next) in cases {
+            let actual = version("1.2.3.4").bump(&next).expect("valid bump");
+            assert_eq!(expected, actual.to_string());
+        }
+        assert_eq!(
+            "1.3",
+            version("1.2")
+                .bump(&NextVersion::Patch)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn bump_errors() {
+        let max = NumericVersion::new([1, u64::MAX]);
+        let cases = [
+            (
+                version("1.2.3.4"),
+                NextVersion::Custom(SemanticVersion::new(1, 2, 3).into()),
+                BumpError::NotGreater {
+                    current: "1.2.3.4".into(),
+                    next: "1.2.3".into(),
+                },
+            ),
+            (
+                version("1.2.3.4"),
+                NextVersion::PreMinor(None),
+                BumpError::NoPreReleases("numeric".into()),
+            ),
+            (
+                version("1.2.3.4"),
+                NextVersion::Release,
+                BumpError::NoPreReleases("numeric".into()),
+            ),
+            (
+                max.clone(),
+                NextVersion::Patch,
+                BumpError::Overflow(max.to_string()),
+            ),
+        ];
+        for (current, next, expected) in cases {
+            assert_eq!(Err(expected), current.bump(&next));
+        }
+    }
+}
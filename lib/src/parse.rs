@@ -0,0 +1,493 @@
+use std::borrow::Cow;
+
+use crate::{
+    layout::{Layout, LineEnding, LinkLine, LinkPlace, PlacedLink},
+    release::ReleaseTitle,
+    util::trim_to_optcow_borrow,
+    Changelog, LinkStyle, ParseError, Release, Unreleased,
+};
+
+/// Dashes that separate a release date from its title.
+const DATE_SEPARATORS: [char; 3] = ['-', '–', '—'];
+
+impl<'c> Changelog<'c> {
+    /// Parse a Keep a Changelog formatted Markdown document.
+    ///
+    /// All text in the returned [`Changelog`] is borrowed from `input`.
+    ///
+    /// Release urls are read from both link reference definitions and inline
+    /// links in the headings. If any heading has an inline link, the
+    /// changelog is written back with [`LinkStyle::Inline`].
+    pub fn parse(input: &'c str) -> Result<Self, ParseError> {
+        Self::parse_with_spans(input).map(|(changelog, _)| changelog)
+    }
+
+    /// Parse like [`Changelog::parse`], also returning where the release
+    /// headings are in the `input`.
+    pub(crate) fn parse_with_spans(input: &'c str) -> Result<(Self, Spans<'c>), ParseError> {
+        let lines = split_lines(input);
+        let headings = find_release_headings(&lines);
+
+        let preamble_end = headings.first().copied().unwrap_or(lines.len());
+        let mut links = Vec::new();
+        let place = match headings.is_empty() {
+            true => LinkPlace::End,
+            false => LinkPlace::Header,
+        };
+        let preamble = body_slice(input, &lines, 0, preamble_end, place, &mut links);
+
+        let mut unreleased: Option<Release<'c>> = None;
+        let mut releases = Vec::new();
+        let mut unreleased_line = None;
+        let mut release_lines = Vec::new();
+        let mut link_style = LinkStyle::Reference;
+
+        for (i, &line_index) in headings.iter().enumerate() {
+            let body_end = headings.get(i + 1).copied().unwrap_or(lines.len());
+            let heading = parse_heading(lines[line_index].text, line_index + 1)?;
+            if heading.url.is_some() {
+                link_style = LinkStyle::Inline;
+            }
+            let is_unreleased = is_unreleased_title(heading.title);
+            let title = match is_unreleased {
+                // keep the title as written in the source
+                true => ReleaseTitle::Title(Cow::Borrowed(heading.title)),
+                false => ReleaseTitle::from(heading.title),
+            };
+            let place = match body_end == lines.len() {
+                true => LinkPlace::End,
+                false => LinkPlace::After(title.clone()),
+            };
+            let content = body_slice(input, &lines, line_index + 1, body_end, place, &mut links);
+
+            let release = Release {
+                title,
+                url: heading.url.map(Cow::Borrowed),
+                date: heading.date.map(Cow::Borrowed),
+                content,
+                yanked: heading.yanked,
+            };
+
+            if is_unreleased {
+                if unreleased.is_some() {
+                    return Err(ParseError::DuplicateUnreleased(line_index + 1));
+                }
+                unreleased = Some(release);
+                unreleased_line = Some(line_index);
+            } else {
+                releases.push(release);
+                release_lines.push(line_index);
+            }
+        }
+
+        let mut header = preamble;
+        if unreleased.is_none() && header.as_deref().is_some_and(looks_like_content) {
+            // a document made up of just the unreleased changes
+            unreleased = Some(Unreleased::with_content(header.take().unwrap()));
+        }
+
+        let mut changelog = Changelog {
+            header,
+            unreleased: unreleased.unwrap_or_else(Unreleased::empty),
+            releases,
+            misc_links: Vec::new(),
+            link_style,
+            layout: Layout {
+                line_ending: LineEnding::detect(input),
+                links: Vec::new(),
+            },
+        };
+        changelog.assign_links(links);
+
+        let spans = Spans {
+            lines,
+            unreleased: unreleased_line,
+            releases: release_lines,
+        };
+        Ok((changelog, spans))
+    }
+
+    /// Attach link reference definitions to their matching releases.
+    ///
+    /// Any definition not used by a release is kept in `misc_links`. Where
+    /// each definition was is kept in the layout.
+    fn assign_links(&mut self, links: Vec<FoundLink<'c>>) {
+        for FoundLink {
+            definition: link,
+            place,
+            blank_lines,
+        } in links
+        {
+            let release = std::iter::once(&mut self.unreleased)
+                .chain(self.releases.iter_mut())
+                .find(|rel| rel.url.is_none() && title_matches_label(&rel.title, link.label));
+
+            let link = match release {
+                Some(release) => {
+                    release.url = Some(Cow::Borrowed(link.url));
+                    PlacedLink::Release(release.title.clone(), Cow::Borrowed(link.line))
+                }
+                None => {
+                    self.misc_links.push(Cow::Borrowed(link.line));
+                    PlacedLink::Misc(self.misc_links.len() - 1)
+                }
+            };
+            self.layout.links.push(LinkLine {
+                place,
+                blank_lines,
+                link,
+            });
+        }
+    }
+}
+
+/// Where the parts of a parsed changelog are in its input.
+#[derive(Debug)]
+pub(crate) struct Spans<'c> {
+    pub(crate) lines: Vec<Line<'c>>,
+    /// Line index of the `Unreleased` heading, if there is one.
+    pub(crate) unreleased: Option<usize>,
+    /// Line index of each release heading, in the order of the releases.
+    pub(crate) releases: Vec<usize>,
+}
+
+impl<'c> Spans<'c> {
+    /// Index of the line containing the byte `offset`.
+    pub(crate) fn line_at(&self, offset: usize) -> usize {
+        self.lines
+            .partition_point(|line| line.start <= offset)
+            .saturating_sub(1)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Line<'c> {
+    pub(crate) text: &'c str,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Heading<'c> {
+    title: &'c str,
+    url: Option<&'c str>,
+    date: Option<&'c str>,
+    yanked: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct LinkDefinition<'c> {
+    pub(crate) label: &'c str,
+    pub(crate) url: &'c str,
+    line: &'c str,
+}
+
+/// A link reference definition at the end of a part of the input.
+struct FoundLink<'c> {
+    definition: LinkDefinition<'c>,
+    place: LinkPlace<'c>,
+    /// Empty lines between the definition and the line before it.
+    blank_lines: usize,
+}
+
+fn split_lines(input: &str) -> Vec<Line<'_>> {
+    let mut start = 0;
+    input
+        .split_inclusive('\n')
+        .map(|raw| {
+            let text = raw.trim_end_matches(['\n', '\r']);
+            let line = Line {
+                text,
+                start,
+                end: start + text.len(),
+            };
+            start += raw.len();
+            line
+        })
+        .collect()
+}
+
+/// Indexes of all level 2 headings outside of fenced code blocks.
+fn find_release_headings(lines: &[Line]) -> Vec<usize> {
+    let mut in_fence = false;
+    let mut headings = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.text.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence && is_release_heading(line.text) {
+            headings.push(i);
+        }
+    }
+    headings
+}
+
+fn is_release_heading(line: &str) -> bool {
+    line.starts_with("## ") || line == "##"
+}
+
+fn is_unreleased_title(title: &str) -> bool {
+    title.eq_ignore_ascii_case("unreleased")
+}
+
+/// Content of the lines `start..end` with surrounding whitespace removed.
+///
+/// Link reference definitions at the end of the block are not part of the
+/// content, they are pushed onto `links` at `place` instead.
+fn body_slice<'c>(
+    input: &'c str,
+    lines: &[Line<'c>],
+    start: usize,
+    mut end: usize,
+    place: LinkPlace<'c>,
+    links: &mut Vec<FoundLink<'c>>,
+) -> Option<Cow<'c, str>> {
+    let links_start = links.len();
+    while end > start {
+        let line = lines[end - 1].text;
+        if line.trim().is_empty() {
+            // empty lines above a definition belong to it
+            if let Some(link) = links[links_start..].last_mut() {
+                link.blank_lines += 1;
+            }
+        } else if let Some(definition) = parse_link_definition(line) {
+            links.push(FoundLink {
+                definition,
+                place: place.clone(),
+                blank_lines: 0,
+            });
+        } else {
+            break;
+        }
+        end -= 1;
+    }
+    // definitions were collected from the bottom up
+    links[links_start..].reverse();
+
+    match end > start {
+        true => trim_to_optcow_borrow(&input[lines[start].start..lines[end - 1].end]),
+        false => None,
+    }
+}
+
+/// Parse the text of a `## ` heading line.
+///
+/// Supported forms:
+/// - `## [title] - date`
+/// - `## [title](url) - date`
+/// - `## title - date`
+///
+/// The date is always optional, and may be followed by a `[YANKED]` tag. It
+/// may be separated from the title by a hyphen, an en dash or an em dash.
+fn parse_heading(line: &str, line_number: usize) -> Result<Heading<'_>, ParseError> {
+    let text = line.trim_start_matches('#').trim();
+
+    let (title, url, rest) = match text.strip_prefix('[') {
+        Some(text) => {
+            let close = text
+                .find(']')
+                .ok_or(ParseError::MalformedHeading(line_number))?;
+            let (title, rest) = (&text[..close], &text[close + 1..]);
+            match rest.strip_prefix('(') {
+                Some(rest) => {
+                    let close = rest
+                        .find(')')
+                        .ok_or(ParseError::MalformedHeading(line_number))?;
+                    (title, Some(rest[..close].trim()), &rest[close + 1..])
+                }
+                None => (title, None, rest),
+            }
+        }
+        None => match find_date_separator(text) {
+            Some(index) => (&text[..index], None, &text[index..]),
+            None => (text, None, ""),
+        },
+    };
+
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(ParseError::MissingTitle(line_number));
+    }
+
+    let rest = rest.trim();
+    let (rest, yanked) = strip_yanked(rest);
+    let date = rest.strip_prefix(DATE_SEPARATORS).unwrap_or(rest).trim();
+
+    Ok(Heading {
+        title,
+        url: url.filter(|url| !url.is_empty()),
+        date: Some(date).filter(|date| !date.is_empty()),
+        yanked,
+    })
+}
+
+/// Start of the first dash between spaces, such as ` - ` or ` – `.
+fn find_date_separator(text: &str) -> Option<usize> {
+    text.char_indices()
+        .find(|&(index, ch)| {
+            DATE_SEPARATORS.contains(&ch)
+                && text[..index].ends_with(' ')
+                && text[index + ch.len_utf8()..].starts_with(' ')
+        })
+        .map(|(index, _)| index - 1)
+}
+
+/// Remove a trailing `[YANKED]` tag, in any case.
+fn strip_yanked(text: &str) -> (&str, bool) {
+    const YANKED: &str = "[YANKED]";
+    match text.len().checked_sub(YANKED.len()) {
+        Some(index)
+            if text.is_char_boundary(index) && text[index..].eq_ignore_ascii_case(YANKED) =>
+        {
+            (text[..index].trim_end(), true)
+        }
+        _ => (text, false),
+    }
+}
+
+/// Parse a Markdown link reference definition: `[label]: url "optional title"`.
+pub(crate) fn parse_link_definition(line: &str) -> Option<LinkDefinition<'_>> {
+    let trimmed = line.trim();
+    let rest = trimmed.strip_prefix('[')?;
+    let (label, rest) = rest.split_once("]:")?;
+    if label.is_empty() || label.contains(['[', ']']) {
+        return None;
+    }
+    let url = rest.split_whitespace().next()?;
+    Some(LinkDefinition {
+        label,
+        url,
+        line: trimmed,
+    })
+}
+
+fn title_matches_label(title: &ReleaseTitle, label: &str) -> bool {
+    match title {
+        ReleaseTitle::Title(title) => title.eq_ignore_ascii_case(label),
+        ReleaseTitle::SemVer(_) | ReleaseTitle::Numeric(_) => {
+            title.to_string() == label.strip_prefix('v').unwrap_or(label)
+        }
+    }
+}
+
+/// Whether the text before the first release heading is change content
+/// rather than a document header.
+fn looks_like_content(preamble: &str) -> bool {
+    let first = preamble.lines().next().unwrap_or_default();
+    first.starts_with("### ") || first.starts_with("- ") || first.starts_with("* ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_heading(expected: Heading, line: &str) {
+        let actual = parse_heading(line, 1).expect("valid heading");
+        assert_eq!(expected, actual, "heading: `{}`", line);
+    }
+
+    fn heading<'c>(title: &'c str, url: Option<&'c str>, date: Option<&'c str>) -> Heading<'c> {
+        Heading {
+            title,
+            url,
+            date,
+            yanked: false,
+        }
+    }
+
+    fn yanked(heading: Heading) -> Heading {
+        Heading {
+            yanked: true,
+            ..heading
+        }
+    }
+
+    #[test]
+    fn heading_reference_style() {
+        check_heading(
+            heading("1.0.0", None, Some("2022-10-03")),
+            "## [1.0.0] - 2022-10-03",
+        );
+    }
+
+    #[test]
+    fn heading_inline_link() {
+        check_heading(
+            heading("0.2.0", Some("https://a.b/c"), Some("2022-09-20")),
+            "## [0.2.0](https://a.b/c) - 2022-09-20",
+        );
+    }
+
+    #[test]
+    fn heading_no_brackets() {
+        check_heading(
+            heading("1.0.0-beta", None, Some("2022-09-28")),
+            "## 1.0.0-beta - 2022-09-28",
+        );
+    }
+
+    #[test]
+    fn heading_dash_separators() {
+        for dash in ['-', '–', '—'] {
+            let expected = heading("1.0.0", None, Some("2022-01-01"));
+            check_heading(expected, &format!("## [1.0.0] {} 2022-01-01", dash));
+            let expected = heading("1.0.0-rc", None, Some("2022-01-01"));
+            check_heading(expected, &format!("## 1.0.0-rc {} 2022-01-01", dash));
+        }
+    }
+
+    #[test]
+    fn heading_no_date() {
+        check_heading(heading("Unreleased", None, None), "## [Unreleased]");
+        check_heading(heading("Unreleased", None, None), "## Unreleased");
+    }
+
+    #[test]
+    fn heading_yanked() {
+        check_heading(
+            yanked(heading("0.0.5", None, Some("2014-12-13"))),
+            "## [0.0.5] - 2014-12-13 [YANKED]",
+        );
+        check_heading(
+            yanked(heading("0.0.5", Some("https://a.b/c"), Some("2014-12-13"))),
+            "## [0.0.5](https://a.b/c) - 2014-12-13 [yanked]",
+        );
+        check_heading(yanked(heading("0.0.5", None, None)), "## [0.0.5] [YANKED]");
+    }
+
+    #[test]
+    fn heading_errors() {
+        let cases = [
+            (ParseError::MalformedHeading(1), "## [1.0.0 - 2022-10-03"),
+            (
+                ParseError::MalformedHeading(1),
+                "## [1.0.0](http://a - 2022",
+            ),
+            (ParseError::MissingTitle(1), "## [] - 2022-10-03"),
+            (ParseError::MissingTitle(1), "##"),
+        ];
+        for (expected, line) in cases {
+            let actual = parse_heading(line, 1).expect_err("invalid heading");
+            assert_eq!(expected, actual, "heading: `{}`", line);
+        }
+    }
+
+    #[test]
+    fn link_definitions() {
+        let link = parse_link_definition("[1.0.0]: https://a.b/c \"title\"").expect("link");
+        assert_eq!("1.0.0", link.label);
+        assert_eq!("https://a.b/c", link.url);
+
+        let invalids = ["[1.0.0] https://a.b/c", "- [a]: b", "[]: b", "[a]:"];
+        for line in invalids {
+            assert_eq!(None, parse_link_definition(line), "line: `{}`", line);
+        }
+    }
+
+    #[test]
+    fn headings_in_code_fences_ignored() {
+        let input = "## [Unreleased]\n\n```\n## [1.0.0]\n```\n";
+        let lines = split_lines(input);
+        assert_eq!(vec![0], find_release_headings(&lines));
+    }
+}
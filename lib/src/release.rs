@@ -1,11 +1,31 @@
 use std::{borrow::Cow, cmp::Ordering, fmt};
 
 use crate::{
+    numeric_version::cmp_numbers,
     util::{optcow, optcow_to_owned},
-    Release, SemanticVersion,
+    InvalidVersion, NumericVersion, Release, ReleaseDate, SemanticVersion,
 };
 
 impl<'c> Release<'c> {
+    /// Create a release without content or a url.
+    pub fn new<T, D>(title: T, date: D) -> Self
+    where
+        T: Into<ReleaseTitle<'c>>,
+        D: Into<Cow<'c, str>>,
+    {
+        Self {
+            title: title.into(),
+            url: None,
+            date: optcow(date),
+            content: None,
+            yanked: false,
+        }
+    }
+
+    pub fn title(&self) -> &ReleaseTitle<'c> {
+        &self.title
+    }
+
     pub fn title_string(&self) -> String {
         self.title.to_string()
     }
@@ -18,10 +38,38 @@ impl<'c> Release<'c> {
         self.url.as_deref()
     }
 
+    /// The date as written, which may not be a valid date.
     pub fn date(&self) -> Option<&str> {
         self.date.as_deref()
     }
 
+    /// The date, if it is a valid `YYYY-MM-DD` date.
+    pub fn release_date(&self) -> Option<ReleaseDate> {
+        self.date.as_deref()?.parse().ok()
+    }
+
+    pub fn set_date(&mut self, date: ReleaseDate) {
+        self.date = Some(date.into());
+    }
+
+    /// Whether the release is marked `[YANKED]`, as it was pulled after being
+    /// published.
+    pub fn is_yanked(&self) -> bool {
+        self.yanked
+    }
+
+    pub fn set_yanked(&mut self, yanked: bool) {
+        self.yanked = yanked;
+    }
+
+    /// Whether the release has no content besides whitespace.
+    pub fn is_empty(&self) -> bool {
+        match &self.content {
+            Some(content) => content.trim().is_empty(),
+            None => true,
+        }
+    }
+
     pub fn set_content<T: Into<Cow<'c, str>>>(&mut self, content: T) {
         self.content = optcow(content);
     }
@@ -46,6 +94,7 @@ impl<'c> Release<'c> {
             url: optcow_to_owned(self.url.clone()),
             date: optcow_to_owned(self.date.clone()),
             content: optcow_to_owned(self.content.clone()),
+            yanked: self.yanked,
         }
     }
 }
@@ -54,6 +103,8 @@ impl<'c> Release<'c> {
 pub enum ReleaseTitle<'c> {
     Title(Cow<'c, str>),
     SemVer(SemanticVersion<'c>),
+    /// Dot separated numbers that are not a SemVer version, such as `1.2.3.4`.
+    Numeric(NumericVersion),
 }
 
 impl<'c> ReleaseTitle<'c> {
@@ -61,9 +112,37 @@ impl<'c> ReleaseTitle<'c> {
         ReleaseTitle::Title(Cow::Borrowed("Unreleased"))
     }
 
+    /// Parse a SemVer version, or dot separated numbers such as `1.2.3.4`.
+    ///
+    /// Unlike [`From<&str>`], plain titles are an error.
+    pub fn version(input: &'c str) -> Result<Self, InvalidVersion> {
+        match SemanticVersion::try_from(input) {
+            Ok(semver) => Ok(Self::SemVer(semver)),
+            Err(InvalidVersion::NotAVersion(_)) => {
+                NumericVersion::try_from(input).map(Self::Numeric)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn semver(&self) -> Option<&SemanticVersion<'c>> {
+        match self {
+            Self::SemVer(semver) => Some(semver),
+            _ => None,
+        }
+    }
+
+    pub fn numeric(&self) -> Option<&NumericVersion> {
+        match self {
+            Self::Numeric(numeric) => Some(numeric),
+            _ => None,
+        }
+    }
+
     pub fn to_owned(&self) -> ReleaseTitle<'static> {
         match self {
             ReleaseTitle::SemVer(semver) => ReleaseTitle::SemVer(semver.to_owned()),
+            ReleaseTitle::Numeric(numeric) => ReleaseTitle::Numeric(numeric.clone()),
             ReleaseTitle::Title(Cow::Owned(title)) => ReleaseTitle::Title(title.to_owned().into()),
             ReleaseTitle::Title(Cow::Borrowed(title)) => {
                 ReleaseTitle::Title(String::from(*title).into())
@@ -90,21 +169,35 @@ impl<'c> PartialOrd for ReleaseTitle<'c> {
     }
 }
 
+/// Versions are ordered by their numbers, before plain titles.
 impl<'c> Ord for ReleaseTitle<'c> {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Self::Title(_), Self::SemVer(_)) => Ordering::Greater,
-            (Self::SemVer(_), Self::Title(_)) => Ordering::Less,
             (Self::Title(l), Self::Title(r)) => l.cmp(r),
+            (Self::Title(_), _) => Ordering::Greater,
+            (_, Self::Title(_)) => Ordering::Less,
             (Self::SemVer(l), Self::SemVer(r)) => l.cmp(r),
+            (Self::Numeric(l), Self::Numeric(r)) => l.cmp(r),
+            (Self::SemVer(l), Self::Numeric(r)) => cmp_semver_numeric(l, r),
+            (Self::Numeric(l), Self::SemVer(r)) => cmp_semver_numeric(r, l).reverse(),
         }
     }
 }
 
+/// Compare the numbers of the versions, a SemVer version is less than the
+/// same numbers.
+fn cmp_semver_numeric(semver: &SemanticVersion, numeric: &NumericVersion) -> Ordering {
+    let numbers = NumericVersion::from(semver);
+    cmp_numbers(numbers.parts(), numeric.parts()).then(Ordering::Less)
+}
+
 impl<'c> From<&'c str> for ReleaseTitle<'c> {
     fn from(input: &'c str) -> Self {
-        match SemanticVersion::try_from(input) {
-            Ok(semver) => Self::SemVer(semver),
+        if let Ok(semver) = SemanticVersion::try_from(input) {
+            return Self::SemVer(semver);
+        }
+        match NumericVersion::try_from(input) {
+            Ok(numeric) => Self::Numeric(numeric),
             Err(_) => Self::Title(Cow::Borrowed(input)),
         }
     }
@@ -112,9 +205,28 @@ impl<'c> From<&'c str> for ReleaseTitle<'c> {
 
 impl From<String> for ReleaseTitle<'static> {
     fn from(input: String) -> Self {
-        match SemanticVersion::try_owned_from(&input) {
-            Ok(semver) => Self::SemVer(semver),
-            _ => Self::Title(Cow::Owned(input)),
+        if let Ok(semver) = SemanticVersion::try_owned_from(&input) {
+            return Self::SemVer(semver);
+        }
+        match NumericVersion::try_from(input.as_str()) {
+            Ok(numeric) => Self::Numeric(numeric),
+            Err(_) => Self::Title(Cow::Owned(input)),
+        }
+    }
+}
+
+impl<'c> From<SemanticVersion<'c>> for ReleaseTitle<'c> {
+    fn from(semver: SemanticVersion<'c>) -> Self {
+        Self::SemVer(semver)
+    }
+}
+
+/// Three numbers are a SemVer version.
+impl From<NumericVersion> for ReleaseTitle<'static> {
+    fn from(numeric: NumericVersion) -> Self {
+        match numeric.parts() {
+            &[major, minor, patch] => Self::SemVer(SemanticVersion::new(major, minor, patch)),
+            _ => Self::Numeric(numeric),
         }
     }
 }
@@ -124,6 +236,7 @@ impl<'c> fmt::Display for ReleaseTitle<'c> {
         match self {
             Self::Title(s) => s.fmt(f),
             Self::SemVer(s) => s.fmt(f),
+            Self::Numeric(n) => n.fmt(f),
         }
     }
 }
@@ -135,15 +248,21 @@ mod tests {
     #[test]
     fn ordering() {
         let low_to_high = [
+            "0.0.0.1",
+            "0.1",
             "0.1.1",
             "0.1.2",
             "1.0.0-alpha",
+            "1.0.0-alpha.2",
+            "1.0.0-alpha.10",
             "1.0.0-beta",
+            "1.0.0-rc.1",
             "1.0.0",
             "1.0.1",
             "1.1.0",
             "1.1.1",
-            "0.0.0.1",
+            "1.1.1.1",
+            "1.2",
             "bad-title",
             "later-bad-title",
         ];
@@ -0,0 +1,271 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{InvalidDate, ReleaseDate};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+impl ReleaseDate {
+    /// A valid date in the Gregorian calendar between years 0 and 9999.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, InvalidDate> {
+        let valid = year <= 9999
+            && (1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day);
+        match valid {
+            true => Ok(Self { year, month, day }),
+            false => Err(InvalidDate::from(format!(
+                "{:04}-{:02}-{:02}",
+                year, month, day
+            ))),
+        }
+    }
+
+    /// Today's date in UTC.
+    pub fn today() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+
+    /// Date in UTC of a point in time.
+    ///
+    /// Times before 1970-01-01 give that date.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let secs = time
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+        Self::from_timestamp(secs.try_into().unwrap_or(i64::MAX)).unwrap_or(Self::MAX)
+    }
+
+    /// Date in UTC of a Unix timestamp in seconds.
+    pub fn from_timestamp(secs: i64) -> Result<Self, InvalidDate> {
+        let (year, month, day) = civil_from_days(secs.div_euclid(SECONDS_PER_DAY));
+        match u16::try_from(year) {
+            Ok(year) => Self::new(year, month, day),
+            Err(_) => Err(InvalidDate::from(format!(
+                "{}-{:02}-{:02}",
+                year, month, day
+            ))),
+        }
+    }
+
+    /// The day after, or the same date at the end of the supported range.
+    pub(crate) fn next_day(&self) -> Self {
+        if self.day < days_in_month(self.year, self.month) {
+            Self {
+                day: self.day + 1,
+                ..*self
+            }
+        } else if self.month < 12 {
+            Self {
+                month: self.month + 1,
+                day: 1,
+                ..*self
+            }
+        } else if self.year < Self::MAX.year {
+            Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        } else {
+            *self
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    const MAX: Self = Self {
+        year: 9999,
+        month: 12,
+        day: 31,
+    };
+}
+
+/// Parses an ISO 8601 calendar date: `YYYY-MM-DD`.
+impl FromStr for ReleaseDate {
+    type Err = InvalidDate;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidDate::from(input);
+        let bytes = input.as_bytes();
+        let digits = |range: std::ops::Range<usize>| {
+            let part = &bytes[range];
+            match part.iter().all(u8::is_ascii_digit) {
+                true => Ok(part.iter().fold(0, |n, d| n * 10 + u16::from(d - b'0'))),
+                false => Err(err()),
+            }
+        };
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(err());
+        }
+        let year = digits(0..4)?;
+        let month = digits(5..7)? as u8;
+        let day = digits(8..10)? as u8;
+        Self::new(year, month, day).map_err(|_| err())
+    }
+}
+
+impl TryFrom<&str> for ReleaseDate {
+    type Error = InvalidDate;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl<'c> From<ReleaseDate> for Cow<'c, str> {
+    fn from(date: ReleaseDate) -> Self {
+        Cow::Owned(date.to_string())
+    }
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Gregorian calendar date from days since 1970-01-01.
+///
+/// Uses Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> ReleaseDate {
+        ReleaseDate::new(year, month, day).expect("valid date")
+    }
+
+    #[test]
+    fn parse_valid_dates() {
+        let cases = [
+            (date(2022, 10, 3), "2022-10-03"),
+            (date(2000, 2, 29), "2000-02-29"),
+            (date(1999, 12, 31), "1999-12-31"),
+            (date(0, 1, 1), "0000-01-01"),
+        ];
+        for (expected, input) in cases {
+            assert_eq!(Ok(expected), input.parse(), "input: `{}`", input);
+            assert_eq!(input, expected.to_string());
+        }
+    }
+
+    #[test]
+    fn parse_invalid_dates() {
+        let invalids = [
+            "2022-13-45",
+            "Oct 3",
+            "2022-02-29",
+            "1900-02-29",
+            "2022-04-31",
+            "2022-00-10",
+            "2022-10-00",
+            "2022-1-3",
+            "22-10-03",
+            "2022/10/03",
+            "2022-10-03T12:00",
+            "+022-10-03",
+            "",
+        ];
+        for input in invalids {
+            let expected = Err(InvalidDate::from(input));
+            assert_eq!(expected, ReleaseDate::try_from(input), "input: `{}`", input);
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let mut dates = [date(2022, 10, 3), date(2021, 12, 31), date(2022, 9, 20)];
+        dates.sort();
+        assert_eq!(
+            [date(2021, 12, 31), date(2022, 9, 20), date(2022, 10, 3)],
+            dates
+        );
+    }
+
+    #[test]
+    fn next_day() {
+        let cases = [
+            (date(2022, 10, 4), date(2022, 10, 3)),
+            (date(2024, 2, 29), date(2024, 2, 28)),
+            (date(2023, 3, 1), date(2023, 2, 28)),
+            (date(2023, 1, 1), date(2022, 12, 31)),
+            (date(9999, 12, 31), date(9999, 12, 31)),
+        ];
+        for (expected, date) in cases {
+            assert_eq!(expected, date.next_day(), "date: {}", date);
+        }
+    }
+
+    #[test]
+    fn from_timestamp() {
+        let cases = [
+            (date(1970, 1, 1), 0),
+            (date(1970, 1, 1), 86_399),
+            (date(1969, 12, 31), -1),
+            (date(2022, 10, 3), 1_664_820_000),
+        ];
+        for (expected, secs) in cases {
+            assert_eq!(
+                Ok(expected),
+                ReleaseDate::from_timestamp(secs),
+                "secs: {}",
+                secs
+            );
+        }
+        assert!(ReleaseDate::from_timestamp(i64::MAX / 2).is_err());
+    }
+
+    #[test]
+    fn civil_from_days_known_dates() {
+        let cases = [
+            ((1970, 1, 1), 0),
+            ((2000, 2, 29), 11_016),
+            ((2022, 10, 3), 19_268),
+            ((1969, 12, 31), -1),
+        ];
+        for (expected, days) in cases {
+            assert_eq!(expected, civil_from_days(days), "days: {}", days);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use std::{borrow::Cow, fmt, io};
+
+use crate::{
+    layout::{LinkLine, LinkPlace, PlacedLink},
+    parse::parse_link_definition,
+    util::offset,
+    Changelog, LinkStyle, Release,
+};
+
+impl<'c> Changelog<'c> {
+    /// Write the Markdown representation of the changelog to `writer`.
+    pub fn write_to<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Release urls as reference definitions, or an empty iterator when they
+    /// are written inline.
+    fn reference_links(&self) -> impl Iterator<Item = (&Release<'c>, &str)> {
+        self.all_releases()
+            .filter(|_| self.link_style == LinkStyle::Reference)
+            .filter_map(|rel| rel.url.as_deref().map(|url| (rel, url)))
+    }
+
+    fn all_releases(&self) -> impl Iterator<Item = &Release<'c>> {
+        std::iter::once(&self.unreleased).chain(self.releases.iter())
+    }
+}
+
+/// Link reference definitions are written where they were parsed, in the
+/// same line text. Other release urls are written next to the definition of
+/// a neighbouring release, or at the end in release order.
+impl<'c> fmt::Display for Changelog<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = Writer::new(self, f);
+
+        let header_links = self
+            .layout
+            .links
+            .iter()
+            .any(|link| writer.is_at(link, &LinkPlace::Header));
+        if self.header.is_some() || header_links {
+            if let Some(header) = &self.header {
+                writer.text(header)?;
+            }
+            writer.links(&LinkPlace::Header)?;
+        }
+        for release in self.all_releases() {
+            writer.release(release)?;
+            writer.links(&LinkPlace::After(release.title.clone()))?;
+        }
+        writer.links(&LinkPlace::End)?;
+        writer.finish()
+    }
+}
+
+/// Where a release url without a definition in the layout is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Anchor {
+    /// Before the url at this index.
+    Before(usize),
+    /// After the url at this index.
+    After(usize),
+    End,
+}
+
+/// Writes a changelog part by part, with the line endings of its layout.
+struct Writer<'a, 'c, 'f> {
+    changelog: &'a Changelog<'c>,
+    f: &'a mut fmt::Formatter<'f>,
+    newline: &'static str,
+    /// Whether anything was written yet.
+    started: bool,
+    /// Release urls to write as reference definitions, in release order.
+    urls: Vec<(&'a Release<'c>, &'a str)>,
+    /// Where each url goes, `None` when it has a place in the layout.
+    anchors: Vec<Option<Anchor>>,
+}
+
+impl<'a, 'c, 'f> Writer<'a, 'c, 'f> {
+    fn new(changelog: &'a Changelog<'c>, f: &'a mut fmt::Formatter<'f>) -> Self {
+        let urls: Vec<_> = changelog.reference_links().collect();
+        let placed: Vec<_> = urls
+            .iter()
+            .map(|(rel, _)| {
+                changelog
+                    .layout
+                    .links
+                    .iter()
+                    .any(|link| link.release_title() == Some(&rel.title))
+            })
+            .collect();
+        let anchors = (0..urls.len())
+            .map(|i| match placed[i] {
+                true => None,
+                false => Some(
+                    (i + 1..urls.len())
+                        .find(|&j| placed[j])
+                        .map(Anchor::Before)
+                        .or_else(|| (0..i).rev().find(|&j| placed[j]).map(Anchor::After))
+                        .unwrap_or(Anchor::End),
+                ),
+            })
+            .collect();
+
+        Self {
+            changelog,
+            f,
+            newline: changelog.layout.line_ending.as_str(),
+            started: false,
+            urls,
+            anchors,
+        }
+    }
+
+    /// Whether the definition is written at `place`.
+    ///
+    /// Definitions after a release that is gone are written at the end.
+    fn is_at(&self, link: &LinkLine<'c>, place: &LinkPlace<'c>) -> bool {
+        match &link.place {
+            LinkPlace::After(title)
+                if !self.changelog.all_releases().any(|rel| &rel.title == title) =>
+            {
+                place == &LinkPlace::End
+            }
+            link_place => link_place == place,
+        }
+    }
+
+    /// A block of text, after an empty line.
+    fn text(&mut self, text: &str) -> fmt::Result {
+        self.separate(1)?;
+        self.f.write_str(text)
+    }
+
+    fn release(&mut self, release: &Release) -> fmt::Result {
+        self.separate(1)?;
+        fmt_release(release, self.changelog.link_style, self.newline, self.f)
+    }
+
+    /// A line after `blank_lines` empty lines.
+    fn line(&mut self, blank_lines: usize, line: &str) -> fmt::Result {
+        self.separate(blank_lines)?;
+        self.f.write_str(line)
+    }
+
+    fn separate(&mut self, blank_lines: usize) -> fmt::Result {
+        if self.started {
+            for _ in 0..=blank_lines {
+                self.f.write_str(self.newline)?;
+            }
+        }
+        self.started = true;
+        Ok(())
+    }
+
+    /// The definitions at `place`, and at the end any others that have no
+    /// place in the layout.
+    fn links(&mut self, place: &LinkPlace<'c>) -> fmt::Result {
+        let changelog = self.changelog;
+        // empty lines of definitions that are not written are kept
+        let mut carried = 0;
+        let mut written = false;
+        for link in changelog.layout.links.iter() {
+            if !self.is_at(link, place) {
+                continue;
+            }
+            let blank_lines = carried.max(link.blank_lines);
+            match self.placed_link(&link.link, blank_lines)? {
+                true => (carried, written) = (0, true),
+                false => carried = blank_lines,
+            }
+        }
+        if place != &LinkPlace::End {
+            return Ok(());
+        }
+
+        let mut blank_lines = match written {
+            true => carried,
+            false => carried.max(1),
+        };
+        for i in 0..self.urls.len() {
+            if self.anchors[i] == Some(Anchor::End) {
+                self.url_definition(i, blank_lines)?;
+                blank_lines = 0;
+            }
+        }
+        for (index, link) in changelog.misc_links.iter().enumerate() {
+            let placed = changelog
+                .layout
+                .links
+                .iter()
+                .any(|line| matches!(line.link, PlacedLink::Misc(i) if i == index));
+            if !placed {
+                self.line(blank_lines, link)?;
+                blank_lines = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a definition of the layout, returning whether it is still part
+    /// of the changelog.
+    fn placed_link(
+        &mut self,
+        link: &PlacedLink<'c>,
+        mut blank_lines: usize,
+    ) -> Result<bool, fmt::Error> {
+        match link {
+            PlacedLink::Release(title, line) => {
+                let index = match self.urls.iter().position(|(rel, _)| &rel.title == title) {
+                    Some(index) => index,
+                    None => return Ok(false),
+                };
+                for i in 0..self.urls.len() {
+                    if self.anchors[i] == Some(Anchor::Before(index)) {
+                        self.url_definition(i, blank_lines)?;
+                        blank_lines = 0;
+                    }
+                }
+                self.line(blank_lines, &with_url(line, self.urls[index].1))?;
+                for i in 0..self.urls.len() {
+                    if self.anchors[i] == Some(Anchor::After(index)) {
+                        self.url_definition(i, 0)?;
+                    }
+                }
+                Ok(true)
+            }
+            PlacedLink::Misc(index) => match self.changelog.misc_links.get(*index) {
+                Some(link) => self.line(blank_lines, link).map(|_| true),
+                None => Ok(false),
+            },
+        }
+    }
+
+    fn url_definition(&mut self, index: usize, blank_lines: usize) -> fmt::Result {
+        let (release, url) = self.urls[index];
+        let definition = format!("[{}]: {}", release.title, url);
+        self.line(blank_lines, &definition)
+    }
+
+    fn finish(self) -> fmt::Result {
+        self.f.write_str(self.newline)
+    }
+}
+
+/// The definition `line` with its url replaced by `url`.
+fn with_url<'l>(line: &'l str, url: &str) -> Cow<'l, str> {
+    match parse_link_definition(line) {
+        Some(link) if link.url != url => {
+            let start = offset(line, link.url);
+            let end = start + link.url.len();
+            Cow::Owned(format!("{}{}{}", &line[..start], url, &line[end..]))
+        }
+        _ => Cow::Borrowed(line),
+    }
+}
+
+/// Renders the release heading and its content, link definitions are
+/// written by the [`Changelog`].
+impl<'c> fmt::Display for Release<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_release(self, LinkStyle::Reference, "\n", f)
+    }
+}
+
+/// Release heading and content, with its url as an inline link in the
+/// heading for [`LinkStyle::Inline`].
+fn fmt_release(
+    release: &Release,
+    style: LinkStyle,
+    newline: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    match (style, &release.url) {
+        (LinkStyle::Inline, Some(url)) => write!(f, "## [{}]({})", release.title, url)?,
+        _ => write!(f, "## [{}]", release.title)?,
+    }
+    if let Some(date) = &release.date {
+        write!(f, " - {}", date)?;
+    }
+    if release.yanked {
+        f.write_str(" [YANKED]")?;
+    }
+    if let Some(content) = &release.content {
+        write!(f, "{0}{0}{1}", newline, content)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChangelogBuilder, LinkStyle, Release, Unreleased};
+
+    #[test]
+    fn empty_changelog() {
+        assert_eq!(
+            "## [Unreleased]\n",
+            ChangelogBuilder::new().build().to_string()
+        );
+    }
+
+    #[test]
+    fn built_changelog() {
+        let changelog = ChangelogBuilder::new()
+            .header("# Changelog")
+            .unreleased(Unreleased::with("https://a.b/compare", "- Change."))
+            .add_misc_link("[docs]: https://docs.rs")
+            .build();
+        let expected = "# Changelog\n\n\
+            ## [Unreleased]\n\n\
+            - Change.\n\n\
+            [Unreleased]: https://a.b/compare\n\
+            [docs]: https://docs.rs\n";
+        assert_eq!(expected, changelog.to_string());
+    }
+
+    #[test]
+    fn write_to_same_as_display() {
+        let changelog = ChangelogBuilder::new()
+            .header("# Changelog")
+            .unreleased(Unreleased::with_content("- Change."))
+            .build();
+        let mut output = Vec::new();
+        changelog.write_to(&mut output).expect("write to vec");
+        assert_eq!(changelog.to_string().as_bytes(), output);
+    }
+
+    #[test]
+    fn inline_links() {
+        let changelog = ChangelogBuilder::new()
+            .unreleased(Unreleased::with_url("https://a.b/compare"))
+            .add_release(Release::new("1.0.0", "2022-10-03"))
+            .add_misc_link("[docs]: https://docs.rs")
+            .link_style(LinkStyle::Inline)
+            .build();
+        let expected = "## [Unreleased](https://a.b/compare)\n\n\
+            ## [1.0.0] - 2022-10-03\n\n\
+            [docs]: https://docs.rs\n";
+        assert_eq!(expected, changelog.to_string());
+    }
+}
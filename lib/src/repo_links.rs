@@ -0,0 +1,324 @@
+use crate::{ForgeKind, RepoLinks};
+
+impl ForgeKind {
+    /// Guess the forge from the host of a repository url.
+    ///
+    /// Hosts are matched by name, so self-hosted instances such as
+    /// `gitlab.example.com` are recognized.
+    pub fn detect(repository: &str) -> Option<Self> {
+        let host = host(repository).to_ascii_lowercase();
+        if host.contains("github") {
+            Some(Self::GitHub)
+        } else if host.contains("gitlab") {
+            Some(Self::GitLab)
+        } else if host.contains("bitbucket") {
+            Some(Self::Bitbucket)
+        } else if ["gitea", "forgejo", "codeberg"]
+            .iter()
+            .any(|name| host.contains(name))
+        {
+            Some(Self::Gitea)
+        } else {
+            None
+        }
+    }
+
+    /// Compare, tag and issue url templates.
+    fn templates(&self) -> [&'static str; 3] {
+        match self {
+            Self::GitHub | Self::Gitea | Self::Custom => [
+                "{repo}/compare/{previous}...{current}",
+                "{repo}/releases/tag/{tag}",
+                "{repo}/issues/{issue}",
+            ],
+            Self::GitLab => [
+                "{repo}/-/compare/{previous}...{current}",
+                "{repo}/-/tags/{tag}",
+                "{repo}/-/issues/{issue}",
+            ],
+            Self::Bitbucket => [
+                "{repo}/branches/compare/{current}%0D{previous}",
+                "{repo}/src/{tag}",
+                "{repo}/issues/{issue}",
+            ],
+        }
+    }
+}
+
+impl RepoLinks {
+    /// Links for a repository, detecting the forge from its host.
+    ///
+    /// Unknown hosts use the GitHub url format, which is shared by Gitea and
+    /// Forgejo.
+    pub fn new<T: AsRef<str>>(repository: T) -> Self {
+        let repository = repository.as_ref();
+        let forge = ForgeKind::detect(repository).unwrap_or(ForgeKind::GitHub);
+        Self::with_forge(repository, forge)
+    }
+
+    /// Links for a repository hosted on the given forge.
+    pub fn with_forge<T: AsRef<str>>(repository: T, forge: ForgeKind) -> Self {
+        let [compare, tag, issue] = forge.templates();
+        Self {
+            repository: normalize_repository(repository.as_ref()),
+            forge,
+            compare: compare.into(),
+            tag: tag.into(),
+            issue: issue.into(),
+        }
+    }
+
+    /// Use a custom compare url template.
+    ///
+    /// Supports the `{repo}`, `{previous}` and `{current}` placeholders.
+    pub fn compare_template<T: Into<String>>(mut self, template: T) -> Self {
+        self.compare = template.into();
+        self.forge = ForgeKind::Custom;
+        self
+    }
+
+    /// Use a custom tag url template.
+    ///
+    /// Supports the `{repo}` and `{tag}` placeholders.
+    pub fn tag_template<T: Into<String>>(mut self, template: T) -> Self {
+        self.tag = template.into();
+        self.forge = ForgeKind::Custom;
+        self
+    }
+
+    /// Use a custom issue url template.
+    ///
+    /// Supports the `{repo}` and `{issue}` placeholders.
+    pub fn issue_template<T: Into<String>>(mut self, template: T) -> Self {
+        self.issue = template.into();
+        self.forge = ForgeKind::Custom;
+        self
+    }
+
+    /// Repository url without a trailing slash or `.git` suffix.
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    pub fn forge(&self) -> ForgeKind {
+        self.forge
+    }
+
+    /// Url comparing the `previous` tag to the `current` tag or branch.
+    pub fn compare_url(&self, previous: &str, current: &str) -> String {
+        self.fill(&self.compare)
+            .replace("{previous}", previous)
+            .replace("{current}", current)
+    }
+
+    /// Url for the unreleased changes since the `previous` tag.
+    pub fn unreleased_url(&self, previous: &str) -> String {
+        self.compare_url(previous, "HEAD")
+    }
+
+    pub fn tag_url(&self, tag: &str) -> String {
+        self.fill(&self.tag).replace("{tag}", tag)
+    }
+
+    pub fn issue_url(&self, issue: u64) -> String {
+        self.fill(&self.issue)
+            .replace("{issue}", &issue.to_string())
+    }
+
+    /// Whether `url` is in the format of the compare url template.
+    pub fn is_compare_url(&self, url: &str) -> bool {
+        matches_template(&self.fill(&self.compare), url)
+    }
+
+    /// Whether `url` is in the format of the tag url template.
+    pub fn is_tag_url(&self, url: &str) -> bool {
+        matches_template(&self.fill(&self.tag), url)
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template.replace("{repo}", &self.repository)
+    }
+}
+
+/// Whether `url` is the `template` with any text in place of its
+/// placeholders.
+fn matches_template(template: &str, url: &str) -> bool {
+    let mut pieces = Vec::new();
+    let mut rest = template;
+    while let Some((start, end)) = rest
+        .find('{')
+        .and_then(|start| Some((start, start + rest[start..].find('}')?)))
+    {
+        pieces.push(&rest[..start]);
+        rest = &rest[end + 1..];
+    }
+    pieces.push(rest);
+
+    let (first, pieces) = pieces.split_first().expect("at least one piece");
+    let Some(mut url) = url.strip_prefix(first) else {
+        return false;
+    };
+    let Some((last, pieces)) = pieces.split_last() else {
+        return url.is_empty();
+    };
+    for piece in pieces {
+        match url.find(piece) {
+            Some(index) => url = &url[index + piece.len()..],
+            None => return false,
+        }
+    }
+    url.ends_with(last)
+}
+
+/// Host part of a url, or of an SSH style `git@host:path` address.
+fn host(repository: &str) -> &str {
+    let rest = match repository.split_once("://") {
+        Some((_, rest)) => rest,
+        None => repository,
+    };
+    let rest = rest.rsplit_once('@').map_or(rest, |(_, rest)| rest);
+    rest.split(['/', ':']).next().unwrap_or_default()
+}
+
+/// Https url for a repository, without a trailing slash or `.git` suffix.
+fn normalize_repository(repository: &str) -> String {
+    let repository = repository.trim().trim_end_matches('/');
+    let repository = repository.strip_suffix(".git").unwrap_or(repository);
+    match repository.strip_prefix("git@") {
+        Some(rest) => format!("https://{}", rest.replacen(':', "/", 1)),
+        None => repository.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_urls(expected: [&str; 3], links: &RepoLinks) {
+        let actual = [
+            links.compare_url("v1.0.0", "v1.1.0"),
+            links.tag_url("v1.1.0"),
+            links.issue_url(42),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn github() {
+        let links = RepoLinks::new("https://github.com/sonro/chog");
+        assert_eq!(ForgeKind::GitHub, links.forge());
+        check_urls(
+            [
+                "https://github.com/sonro/chog/compare/v1.0.0...v1.1.0",
+                "https://github.com/sonro/chog/releases/tag/v1.1.0",
+                "https://github.com/sonro/chog/issues/42",
+            ],
+            &links,
+        );
+    }
+
+    #[test]
+    fn gitlab_nested_subgroups() {
+        let links = RepoLinks::new("https://gitlab.com/group/sub/project/");
+        assert_eq!(ForgeKind::GitLab, links.forge());
+        check_urls(
+            [
+                "https://gitlab.com/group/sub/project/-/compare/v1.0.0...v1.1.0",
+                "https://gitlab.com/group/sub/project/-/tags/v1.1.0",
+                "https://gitlab.com/group/sub/project/-/issues/42",
+            ],
+            &links,
+        );
+    }
+
+    #[test]
+    fn gitea_and_forgejo() {
+        for repo in [
+            "https://codeberg.org/user/repo",
+            "https://gitea.com/user/repo",
+        ] {
+            let links = RepoLinks::new(repo);
+            assert_eq!(ForgeKind::Gitea, links.forge());
+            check_urls(
+                [
+                    &format!("{}/compare/v1.0.0...v1.1.0", repo),
+                    &format!("{}/releases/tag/v1.1.0", repo),
+                    &format!("{}/issues/42", repo),
+                ],
+                &links,
+            );
+        }
+    }
+
+    #[test]
+    fn bitbucket() {
+        let links = RepoLinks::new("https://bitbucket.org/team/repo.git");
+        assert_eq!(ForgeKind::Bitbucket, links.forge());
+        check_urls(
+            [
+                "https://bitbucket.org/team/repo/branches/compare/v1.1.0%0Dv1.0.0",
+                "https://bitbucket.org/team/repo/src/v1.1.0",
+                "https://bitbucket.org/team/repo/issues/42",
+            ],
+            &links,
+        );
+    }
+
+    #[test]
+    fn match_url_templates() {
+        let links = RepoLinks::new("https://bitbucket.org/team/repo");
+        let compare = links.compare_url("v1.0.0", "v1.1.0");
+        let tag = links.tag_url("v1.1.0");
+        assert!(links.is_compare_url(&compare));
+        assert!(!links.is_compare_url(&tag));
+        assert!(links.is_tag_url(&tag));
+        assert!(!links.is_tag_url(&compare));
+        assert!(!links.is_compare_url("https://github.com/team/repo/compare/v1...v2"));
+
+        let links = RepoLinks::new("https://cgit.example.com/repo")
+            .compare_template("{repo}/diff/?id={current}&id2={previous}");
+        assert!(links.is_compare_url(&links.compare_url("v1.0.0", "v1.1.0")));
+        assert!(!links.is_compare_url("https://cgit.example.com/repo/diff/?id=v1.1.0"));
+    }
+
+    #[test]
+    fn self_hosted_with_forge() {
+        let links = RepoLinks::with_forge("https://git.example.com/a/b/c", ForgeKind::GitLab);
+        assert_eq!(
+            "https://git.example.com/a/b/c/-/compare/v1.0.0...HEAD",
+            links.unreleased_url("v1.0.0")
+        );
+    }
+
+    #[test]
+    fn self_hosted_detected() {
+        let links = RepoLinks::new("https://gitlab.example.com/group/project");
+        assert_eq!(ForgeKind::GitLab, links.forge());
+        let links = RepoLinks::new("https://git.example.com/group/project");
+        assert_eq!(ForgeKind::GitHub, links.forge());
+    }
+
+    #[test]
+    fn ssh_address() {
+        let links = RepoLinks::new("git@gitlab.com:group/project.git");
+        assert_eq!(ForgeKind::GitLab, links.forge());
+        assert_eq!("https://gitlab.com/group/project", links.repository());
+    }
+
+    #[test]
+    fn custom_templates() {
+        let links = RepoLinks::new("https://cgit.example.com/repo")
+            .compare_template("{repo}/diff/?id={current}&id2={previous}")
+            .tag_template("{repo}/tag/?h={tag}")
+            .issue_template("https://bugs.example.com/{issue}");
+        assert_eq!(ForgeKind::Custom, links.forge());
+        check_urls(
+            [
+                "https://cgit.example.com/repo/diff/?id=v1.1.0&id2=v1.0.0",
+                "https://cgit.example.com/repo/tag/?h=v1.1.0",
+                "https://bugs.example.com/42",
+            ],
+            &links,
+        );
+    }
+}
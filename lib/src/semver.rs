@@ -1,38 +1,209 @@
-use std::{borrow::Cow, cmp::Ordering, fmt};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
-use crate::{util::optcow_to_owned, InvalidVersion, SemanticVersion};
+use crate::{
+    util::optcow_to_owned, BumpError, Identifier, InvalidVersion, NextVersion, ReleaseTitle,
+    SemanticVersion, VersionScheme,
+};
 
 impl<'v> SemanticVersion<'v> {
-    pub fn new(major: u16, minor: u16, patch: u16) -> Self {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
         Self {
             major,
             minor,
             patch,
-            label: None,
+            pre: Vec::new(),
+            build: None,
         }
     }
 
+    /// A pre-release version, the `label` is split into dot separated
+    /// [`Identifier`]s.
+    ///
+    /// # Panics
+    ///
+    /// If a numeric identifier of the label does not fit in a `u64`.
     pub fn new_with_label(
-        major: u16,
-        minor: u16,
-        patch: u16,
+        major: u64,
+        minor: u64,
+        patch: u64,
         label: impl Into<Cow<'v, str>>,
     ) -> Self {
         Self {
             major,
             minor,
             patch,
-            label: Some(label.into()),
+            pre: split_identifiers(label.into()).expect("numeric identifier should fit in a u64"),
+            build: None,
         }
     }
 
+    /// The same version with `build` metadata, such as `exp.sha.5114f85`.
+    pub fn with_build(mut self, build: impl Into<Cow<'v, str>>) -> Self {
+        self.build = Some(build.into());
+        self
+    }
+
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    /// The pre-release identifiers joined by dots, such as `rc.1`.
+    pub fn label(&self) -> Option<String> {
+        match self.is_pre_release() {
+            true => Some(
+                self.pre
+                    .iter()
+                    .map(Identifier::to_string)
+                    .collect::<Vec<_>>()
+                    .join("."),
+            ),
+            false => None,
+        }
+    }
+
+    pub fn pre_release(&self) -> &[Identifier<'v>] {
+        &self.pre
+    }
+
+    pub fn is_pre_release(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    /// Build metadata after the `+`, such as `20130313144700`.
+    pub fn build(&self) -> Option<&str> {
+        self.build.as_deref()
+    }
+
+    /// Whether the version is `1.0.0` or above.
+    pub fn is_stable(&self) -> bool {
+        self.major > 0
+    }
+
+    /// The version following this one.
+    ///
+    /// Lower components are reset to zero and any pre-release label is
+    /// dropped. A pre-release is promoted to its release version when the
+    /// bumped component is the lowest non-zero one, so `1.0.0-rc.1` bumps
+    /// to `1.0.0` with [`NextVersion::Major`].
+    ///
+    /// The pre-release keywords follow npm: `premajor`, `preminor` and
+    /// `prepatch` always increase their number and start counting from
+    /// zero, so `0.2.0` bumps to `0.3.0-beta.0` with a `beta` identifier.
+    /// `prerelease` increases the last number of a pre-release, or starts
+    /// over when the identifier changes, and `release` drops the label.
+    ///
+    /// A custom version must be greater than this one, and
+    /// [`NextVersion::Auto`] must be resolved first.
+    pub fn bump<'a>(&self, next: &NextVersion<'a>) -> Result<SemanticVersion<'a>, BumpError> {
+        let overflow = || BumpError::Overflow(self.to_string());
+        let inc = |n: u64| n.checked_add(1).ok_or_else(overflow);
+        let pre = self.is_pre_release();
+        let (major, minor, patch) = (self.major, self.minor, self.patch);
+        let start_pre = |preid: &Option<Cow<'a, str>>| -> Result<_, BumpError> {
+            let mut identifiers = preid_identifiers(preid)?;
+            identifiers.push(Identifier::Numeric(0));
+            Ok(identifiers)
+        };
+
+        let (major, minor, patch, pre) = match next {
+            NextVersion::Major if pre && minor == 0 && patch == 0 => (major, 0, 0, Vec::new()),
+            NextVersion::Major => (inc(major)?, 0, 0, Vec::new()),
+            NextVersion::Minor if pre && patch == 0 => (major, minor, 0, Vec::new()),
+            NextVersion::Minor => (major, inc(minor)?, 0, Vec::new()),
+            NextVersion::Patch if pre => (major, minor, patch, Vec::new()),
+            NextVersion::Patch => (major, minor, inc(patch)?, Vec::new()),
+            NextVersion::PreMajor(preid) => (inc(major)?, 0, 0, start_pre(preid)?),
+            NextVersion::PreMinor(preid) => (major, inc(minor)?, 0, start_pre(preid)?),
+            NextVersion::PreRelease(preid) if pre => (major, minor, patch, self.next_pre(preid)?),
+            // `prerelease` of a release starts a pre-release of the next patch
+            NextVersion::PrePatch(preid) | NextVersion::PreRelease(preid) => {
+                (major, minor, inc(patch)?, start_pre(preid)?)
+            }
+            NextVersion::Release if pre => (major, minor, patch, Vec::new()),
+            NextVersion::Release => return Err(BumpError::NotPreRelease(self.to_string())),
+            NextVersion::Custom(ReleaseTitle::SemVer(custom)) => {
+                return self.ensure_greater(custom.clone())
+            }
+            NextVersion::Custom(custom) => {
+                return Err(BumpError::NotInScheme {
+                    version: custom.to_string(),
+                    scheme: VersionScheme::SemVer.to_string(),
+                })
+            }
+            NextVersion::Auto => return Err(BumpError::Unresolved),
+        };
+
+        self.ensure_greater(SemanticVersion {
+            major,
+            minor,
+            patch,
+            pre,
+            build: None,
+        })
+    }
+
+    fn ensure_greater<'a>(
+        &self,
+        next: SemanticVersion<'a>,
+    ) -> Result<SemanticVersion<'a>, BumpError> {
+        match &next > self {
+            true => Ok(next),
+            false => Err(BumpError::NotGreater {
+                current: self.to_string(),
+                next: next.to_string(),
+            }),
+        }
+    }
+
+    /// Pre-release identifiers following the current ones.
+    fn next_pre<'a>(&self, preid: &Option<Cow<'a, str>>) -> Result<Vec<Identifier<'a>>, BumpError> {
+        let overflow = || BumpError::Overflow(self.to_string());
+        let mut identifiers = preid_identifiers(preid)?;
+
+        if identifiers.is_empty() {
+            // increase the last number, or count from zero
+            let mut identifiers: Vec<_> = self.pre.iter().map(Identifier::to_owned).collect();
+            match identifiers.iter_mut().rev().find_map(|id| match id {
+                Identifier::Numeric(n) => Some(n),
+                Identifier::AlphaNumeric(_) => None,
+            }) {
+                Some(n) => *n = n.checked_add(1).ok_or_else(overflow)?,
+                None => identifiers.push(Identifier::Numeric(0)),
+            }
+            return Ok(identifiers);
+        }
+
+        // increase the number following a matching identifier
+        let next = match self.pre.get(identifiers.len()) {
+            Some(Identifier::Numeric(n)) if self.pre.starts_with(&identifiers) => {
+                n.checked_add(1).ok_or_else(overflow)?
+            }
+            _ => 0,
+        };
+        identifiers.push(Identifier::Numeric(next));
+        Ok(identifiers)
+    }
+
     pub fn to_owned(&'v self) -> SemanticVersion<'static> {
-        let label = optcow_to_owned(self.label.clone());
         SemanticVersion {
             major: self.major,
             minor: self.minor,
             patch: self.patch,
-            label,
+            pre: self.pre.iter().map(Identifier::to_owned).collect(),
+            build: optcow_to_owned(self.build.clone()),
         }
     }
 
@@ -45,22 +216,42 @@ impl<'v> SemanticVersion<'v> {
 impl<'v> TryFrom<&'v str> for SemanticVersion<'v> {
     type Error = InvalidVersion;
 
+    /// Parse a version as written in a changelog heading or on the command
+    /// line, where a leading `v` is allowed. Use [`TagFormat::parse_tag`] for
+    /// git tags.
+    ///
+    /// [`TagFormat::parse_tag`]: crate::TagFormat::parse_tag
     fn try_from(input: &'v str) -> Result<Self, Self::Error> {
         try_from_str(input)
     }
 }
 
 // Keep as long function to make use of lazily evaulated error value
-fn try_from_str(input: &str) -> Result<SemanticVersion, InvalidVersion> {
+fn try_from_str(input: &str) -> Result<SemanticVersion<'_>, InvalidVersion> {
     let err = || InvalidVersion::from(input);
-    // parse &str into u16 or our lazy error
-    let parse_part = |part: &str| part.parse::<u16>().map_err(|_| err());
+    // digits that do not fit are a version, but an unsupported one
+    let too_large = || InvalidVersion::TooLarge(input.into());
+    // parse &str into u64 or our lazy error
+    let parse_part = |part: &str| {
+        part.parse::<u64>().map_err(|_| {
+            match !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()) {
+                true => too_large(),
+                false => err(),
+            }
+        })
+    };
     // try to parse semver part
     // map a non-existant value to our lazy error
     let part_from_split = |part: Option<&str>| part.ok_or_else(err).map(parse_part)?;
 
     // remove `v` prefix if it exists
     let input = input.strip_prefix('v').unwrap_or(input);
+    // split off build metadata (x.x.x[-label]+build)
+    let (input, build) = match input.split_once('+') {
+        Some((_, build)) if !build.split('.').all(is_identifier) => return Err(err()),
+        Some((input, build)) => (input, Some(Cow::Borrowed(build))),
+        None => (input, None),
+    };
 
     let mut parts = input.splitn(3, '.');
     // major.*.*
@@ -72,27 +263,60 @@ fn try_from_str(input: &str) -> Result<SemanticVersion, InvalidVersion> {
         Some(part) => match part.split_once('-') {
             // label (x.x.x-label) exists
             // just parse the patch part as a number
-            Some((patch, label)) => (parse_part(patch)?, Some(label.into())),
+            Some((patch, label)) => (parse_part(patch)?, Some(label)),
             // label doesn't exist
             None => (parse_part(part)?, None),
         },
         None => return Err(err()),
     };
+    // every dot separated identifier needs a valid value
+    if label.is_some_and(|label| !label.split('.').all(is_pre_release_identifier)) {
+        return Err(err());
+    }
+    let pre = match label {
+        Some(label) => split_identifiers(label.into()).map_err(|_| too_large())?,
+        None => Vec::new(),
+    };
 
     Ok(SemanticVersion {
         major,
         minor,
         patch,
-        label,
+        pre,
+        build,
     })
 }
 
 impl<'v> fmt::Display for SemanticVersion<'v> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.label {
-            Some(label) => write!(f, "{}.{}.{}-{}", self.major, self.minor, self.patch, label),
-            None => write!(f, "{}.{}.{}", self.major, self.minor, self.patch),
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        for (i, identifier) in self.pre.iter().enumerate() {
+            f.write_str(if i == 0 { "-" } else { "." })?;
+            identifier.fmt(f)?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "+{}", build)?;
         }
+        Ok(())
+    }
+}
+
+/// Equal when the versions have the same precedence, ignoring build metadata.
+impl<'v> PartialEq for SemanticVersion<'v> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'v> Eq for SemanticVersion<'v> {}
+
+/// Hashes the parts used for equality, so build metadata is ignored.
+impl<'v> Hash for SemanticVersion<'v> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.major.hash(state);
+        self.minor.hash(state);
+        self.patch.hash(state);
+        self.pre.hash(state);
     }
 }
 
@@ -116,13 +340,116 @@ impl<'v> Ord for SemanticVersion<'v> {
             Ordering::Equal => {}
             ord => return ord,
         }
-        match (&self.label, &other.label) {
-            (Some(label), Some(other)) => label.cmp(other),
-            (Some(_), None) => Ordering::Less,
-            (None, Some(_)) => Ordering::Greater,
-            (None, None) => Ordering::Equal,
+        // a pre-release has lower precedence than its release, otherwise
+        // identifiers are compared in order and a longer set wins a tie
+        match (self.pre.is_empty(), other.pre.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.pre.cmp(&other.pre),
+        }
+    }
+}
+
+impl<'v> Identifier<'v> {
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Self::Numeric(_))
+    }
+
+    pub fn to_owned(&self) -> Identifier<'static> {
+        match self {
+            Self::Numeric(n) => Identifier::Numeric(*n),
+            Self::AlphaNumeric(text) => Identifier::AlphaNumeric(Cow::Owned(text.to_string())),
         }
     }
+
+    /// Digits without a leading zero are numeric, and must fit in a `u64`.
+    fn parse(text: Cow<'v, str>) -> Result<Self, InvalidVersion> {
+        let numeric =
+            text.bytes().all(|b| b.is_ascii_digit()) && !(text.len() > 1 && text.starts_with('0'));
+        match text.parse() {
+            Ok(n) if numeric => Ok(Self::Numeric(n)),
+            Err(_) if numeric && !text.is_empty() => Err(InvalidVersion::TooLarge(text.into())),
+            _ => Ok(Self::AlphaNumeric(text)),
+        }
+    }
+}
+
+impl<'v> TryFrom<&'v str> for Identifier<'v> {
+    type Error = InvalidVersion;
+
+    fn try_from(text: &'v str) -> Result<Self, Self::Error> {
+        Self::parse(Cow::Borrowed(text))
+    }
+}
+
+impl<'v> fmt::Display for Identifier<'v> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(n) => n.fmt(f),
+            Self::AlphaNumeric(text) => f.write_str(text),
+        }
+    }
+}
+
+impl<'v> PartialOrd for Identifier<'v> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+/// Precedence from section 11 of the SemVer 2.0.0 specification: numeric
+/// identifiers compare by value and are lower than alphanumeric identifiers,
+/// which compare by ASCII order.
+impl<'v> Ord for Identifier<'v> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Numeric(n), Self::Numeric(other)) => n.cmp(other),
+            (Self::Numeric(_), Self::AlphaNumeric(_)) => Ordering::Less,
+            (Self::AlphaNumeric(_), Self::Numeric(_)) => Ordering::Greater,
+            (Self::AlphaNumeric(text), Self::AlphaNumeric(other)) => {
+                text.as_bytes().cmp(other.as_bytes())
+            }
+        }
+    }
+}
+
+/// Identifiers of a pre-release keyword's `preid`, which must be valid.
+fn preid_identifiers<'a>(preid: &Option<Cow<'a, str>>) -> Result<Vec<Identifier<'a>>, BumpError> {
+    let preid = match preid {
+        Some(preid) => preid,
+        None => return Ok(Vec::new()),
+    };
+    let invalid = || BumpError::InvalidPreid(preid.to_string());
+    match preid.split('.').all(is_pre_release_identifier) {
+        true => split_identifiers(preid.clone()).map_err(|_| invalid()),
+        false => Err(invalid()),
+    }
+}
+
+/// Whether the text is a non-empty identifier of ASCII alphanumerics and
+/// hyphens, from section 9 of the SemVer 2.0.0 specification.
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// Whether the text is a pre-release identifier, which is also a number
+/// without leading zeros when it only has digits.
+fn is_pre_release_identifier(text: &str) -> bool {
+    let leading_zero =
+        text.len() > 1 && text.starts_with('0') && text.bytes().all(|b| b.is_ascii_digit());
+    is_identifier(text) && !leading_zero
+}
+
+/// Split a pre-release label on dots, borrowing from the label if possible.
+fn split_identifiers(label: Cow<'_, str>) -> Result<Vec<Identifier<'_>>, InvalidVersion> {
+    match label {
+        Cow::Borrowed(label) => label.split('.').map(Identifier::try_from).collect(),
+        Cow::Owned(label) => label
+            .split('.')
+            .map(|part| Identifier::parse(Cow::Owned(part.to_owned())))
+            .collect(),
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +474,18 @@ mod tests {
             "1.2.3.label",
             "1.2-label.3",
             "v1.2-test",
+            "1.2.3-",
+            "1.2.3-rc..1",
+            "1.2.3-rc.",
+            "1.2.3+",
+            "1.2.3-beta+",
+            "1.2.3+exp..sha",
+            "1.2+3",
+            "1.0.0-rc.01",
+            "1.0.0-00",
+            "1.0.0-rc_1",
+            "1.0.0-beta+exp/sha",
+            "1.0.0-bêta",
         ];
         for v in invalids {
             let actual = match SemanticVersion::try_from(v) {
@@ -157,6 +496,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_from_str_too_large() {
+        let too_large = [
+            "18446744073709551616.0.0",
+            "0.18446744073709551616.0",
+            "0.0.18446744073709551616",
+            "v1.0.99999999999999999999-rc.1",
+            "1.0.0-rc.18446744073709551616",
+            "v1.0.0-18446744073709551616+build.1",
+        ];
+        for v in too_large {
+            let expected = InvalidVersion::TooLarge(v.into());
+            assert_eq!(
+                Err(expected),
+                SemanticVersion::try_from(v),
+                "input: `{}`",
+                v
+            );
+        }
+    }
+
     #[test]
     fn try_from_str_valid_versions() {
         for (input, expected) in valid_inputs_and_semvers() {
@@ -182,8 +542,26 @@ mod tests {
             "0.2.0-beta",
             "0.2.0-pr.1",
             "0.2.0-pr.2",
+            "0.2.0-pr.10",
             "0.2.0",
+            // examples from section 11 of the specification
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
             "1.0.0",
+            // numeric before alphanumeric, alphanumeric in ASCII order
+            "1.0.1-1",
+            "1.0.1-9",
+            "1.0.1-10",
+            "1.0.1-A",
+            "1.0.1-a",
+            "1.0.1-a-b",
+            "1.0.1-ab",
+            "1.0.1",
         ];
         for w in low_to_high.windows(2) {
             let low = SemanticVersion::try_from(w[0]).expect("valid version");
@@ -192,6 +570,145 @@ mod tests {
         }
     }
 
+    fn check_bump(expected: &str, current: &str, next: NextVersion) {
+        let current = SemanticVersion::try_from(current).expect("valid version");
+        let actual = current.bump(&next).expect("valid bump");
+        assert_eq!(expected, actual.to_string(), "{} {:?}", current, next);
+    }
+
+    #[test]
+    fn bump_release_versions() {
+        check_bump("2.0.0", "1.2.3", NextVersion::Major);
+        check_bump("1.3.0", "1.2.3", NextVersion::Minor);
+        check_bump("1.2.4", "1.2.3", NextVersion::Patch);
+        check_bump("1.0.0", "0.9.9", NextVersion::Major);
+        check_bump("0.1.0", "0.0.9", NextVersion::Minor);
+    }
+
+    #[test]
+    fn bump_pre_release_versions() {
+        check_bump("1.0.0", "1.0.0-rc.1", NextVersion::Major);
+        check_bump("2.0.0", "1.1.0-rc.1", NextVersion::Major);
+        check_bump("1.1.0", "1.1.0-beta", NextVersion::Minor);
+        check_bump("1.2.0", "1.1.1-beta", NextVersion::Minor);
+        check_bump("1.1.1", "1.1.1-beta", NextVersion::Patch);
+    }
+
+    #[test]
+    fn bump_pre_release_keywords() {
+        let rc = || Some("rc".into());
+        let beta = || Some("beta".into());
+        let cases = [
+            ("1.0.0-rc.2", "1.0.0-rc.1", NextVersion::PreRelease(rc())),
+            ("1.0.0-rc.0", "1.0.0-beta.3", NextVersion::PreRelease(rc())),
+            ("1.0.0-rc.0", "1.0.0-rc", NextVersion::PreRelease(rc())),
+            ("1.0.0-rc.2", "1.0.0-rc.1", NextVersion::PreRelease(None)),
+            (
+                "1.0.0-rc.2.b",
+                "1.0.0-rc.1.b",
+                NextVersion::PreRelease(None),
+            ),
+            ("1.0.0-beta.0", "1.0.0-beta", NextVersion::PreRelease(None)),
+            ("1.2.4-rc.0", "1.2.3", NextVersion::PreRelease(rc())),
+            ("1.2.4-0", "1.2.3", NextVersion::PreRelease(None)),
+            ("0.3.0-beta.0", "0.2.0", NextVersion::PreMinor(beta())),
+            ("2.0.0-beta.0", "1.2.3", NextVersion::PreMajor(beta())),
+            ("2.0.0-0", "1.0.0-rc.1", NextVersion::PreMajor(None)),
+            ("1.2.4-beta.0", "1.2.3", NextVersion::PrePatch(beta())),
+            ("1.0.0", "1.0.0-rc.2", NextVersion::Release),
+            ("1.2.3", "1.2.3-beta.1+build", NextVersion::Release),
+        ];
+        for (expected, current, next) in cases {
+            check_bump(expected, current, next);
+        }
+    }
+
+    #[test]
+    fn bump_pre_release_errors() {
+        let cases = [
+            (
+                BumpError::NotPreRelease("1.0.0".into()),
+                "1.0.0",
+                NextVersion::Release,
+            ),
+            (
+                BumpError::NotGreater {
+                    current: "1.0.0-rc.1".into(),
+                    next: "1.0.0-beta.0".into(),
+                },
+                "1.0.0-rc.1",
+                NextVersion::PreRelease(Some("beta".into())),
+            ),
+            (
+                BumpError::InvalidPreid("rc..1".into()),
+                "1.0.0",
+                NextVersion::PreMinor(Some("rc..1".into())),
+            ),
+            (
+                BumpError::InvalidPreid("r c".into()),
+                "1.0.0",
+                NextVersion::PreRelease(Some("r c".into())),
+            ),
+            (
+                BumpError::InvalidPreid("rc.18446744073709551616".into()),
+                "1.0.0",
+                NextVersion::PrePatch(Some("rc.18446744073709551616".into())),
+            ),
+        ];
+        for (expected, current, next) in cases {
+            let current = SemanticVersion::try_from(current).expect("valid version");
+            assert_eq!(Err(expected), current.bump(&next), "{} {:?}", current, next);
+        }
+    }
+
+    #[test]
+    fn bump_custom_version() {
+        let custom = SemanticVersion::new_with_label(1, 0, 0, "rc.1");
+        check_bump("1.0.0-rc.1", "0.2.0", NextVersion::Custom(custom.into()));
+    }
+
+    #[test]
+    fn bump_custom_version_not_greater() {
+        let current = SemanticVersion::new(1, 0, 0);
+        for custom in ["1.0.0", "0.9.0", "1.0.0-rc.1"] {
+            let next = NextVersion::Custom(custom.into());
+            let expected = BumpError::NotGreater {
+                current: "1.0.0".into(),
+                next: custom.into(),
+            };
+            assert_eq!(Err(expected), current.bump(&next));
+        }
+    }
+
+    #[test]
+    fn bump_custom_numeric_version_errors() {
+        let next = NextVersion::new("1.2.3.4").expect("valid version");
+        let expected = BumpError::NotInScheme {
+            version: "1.2.3.4".into(),
+            scheme: "semver".into(),
+        };
+        assert_eq!(Err(expected), SemanticVersion::new(1, 0, 0).bump(&next));
+    }
+
+    #[test]
+    fn bump_overflow() {
+        let cases = [
+            (SemanticVersion::new(u64::MAX, 0, 0), NextVersion::Major),
+            (SemanticVersion::new(0, u64::MAX, 0), NextVersion::Minor),
+            (SemanticVersion::new(0, 0, u64::MAX), NextVersion::Patch),
+        ];
+        for (current, next) in cases {
+            let expected = BumpError::Overflow(current.to_string());
+            assert_eq!(Err(expected), current.bump(&next));
+        }
+    }
+
+    #[test]
+    fn bump_auto_unresolved() {
+        let current = SemanticVersion::new(1, 0, 0);
+        assert_eq!(Err(BumpError::Unresolved), current.bump(&NextVersion::Auto));
+    }
+
     fn valid_inputs_and_semvers<'v>() -> Vec<(&'v str, SemanticVersion<'v>)> {
         use crate::SemanticVersion as SV;
         vec![
@@ -206,6 +723,12 @@ mod tests {
             ("200.0.0", SV::new(200, 0, 0)),
             ("0.200.0", SV::new(0, 200, 0)),
             ("0.0.200", SV::new(0, 0, 200)),
+            ("2023.1015.0", SV::new(2023, 1015, 0)),
+            ("1.0.65536", SV::new(1, 0, 65536)),
+            (
+                "18446744073709551615.0.0",
+                SV::new(18446744073709551615, 0, 0),
+            ),
             ("0.0.0-beta", SV::new_with_label(0, 0, 0, "beta")),
             (
                 "1.2.3-label-is-good.1",
@@ -215,6 +738,78 @@ mod tests {
                 "v200.200.200-charlie",
                 SV::new_with_label(200, 200, 200, "charlie"),
             ),
+            (
+                "1.2.3+20130313144700",
+                SV::new(1, 2, 3).with_build("20130313144700"),
+            ),
+            (
+                "1.2.3-beta+exp.sha.5114f85",
+                SV::new_with_label(1, 2, 3, "beta").with_build("exp.sha.5114f85"),
+            ),
+            (
+                "1.0.0-rc.1+build.1-x",
+                SV::new_with_label(1, 0, 0, "rc.1").with_build("build.1-x"),
+            ),
         ]
     }
+
+    #[test]
+    fn build_metadata() {
+        let cases = [
+            (None, "1.2.3"),
+            (Some("20130313144700"), "1.2.3+20130313144700"),
+            (Some("exp.sha.5114f85"), "1.2.3-beta+exp.sha.5114f85"),
+        ];
+        for (expected, input) in cases {
+            let version = SemanticVersion::try_from(input).expect("valid version");
+            assert_eq!(expected, version.build(), "input: `{}`", input);
+        }
+        let version =
+            SemanticVersion::try_from("1.2.3-beta+exp.sha.5114f85").expect("valid version");
+        assert_eq!(Some("beta".into()), version.label());
+    }
+
+    #[test]
+    fn build_metadata_ignored_by_comparisons() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |version: &SemanticVersion| {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        };
+        let a = SemanticVersion::try_from("1.0.0+a").expect("valid version");
+        let b = SemanticVersion::try_from("1.0.0+b").expect("valid version");
+        assert_eq!(Ordering::Equal, a.cmp(&b));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(a.to_string(), b.to_string());
+    }
+
+    #[test]
+    fn bump_drops_build_metadata() {
+        check_bump("1.3.0", "1.2.3+build.5", NextVersion::Minor);
+    }
+
+    #[test]
+    fn pre_release_identifiers() {
+        let version = SemanticVersion::try_from("1.0.0-rc.1.0a.x-y").expect("valid version");
+        let expected = [
+            Identifier::AlphaNumeric("rc".into()),
+            Identifier::Numeric(1),
+            Identifier::AlphaNumeric("0a".into()),
+            Identifier::AlphaNumeric("x-y".into()),
+        ];
+        assert_eq!(expected, version.pre_release());
+        assert_eq!(Some("rc.1.0a.x-y".into()), version.label());
+        assert_eq!(None, SemanticVersion::new(1, 0, 0).label());
+    }
+
+    #[test]
+    fn equal_precedence() {
+        let version = SemanticVersion::try_from("1.0.0-rc.1").expect("valid version");
+        let same = SemanticVersion::new_with_label(1, 0, 0, String::from("rc.1"));
+        assert_eq!(Ordering::Equal, version.cmp(&same));
+        assert_eq!(version, same);
+    }
 }
@@ -0,0 +1,189 @@
+use std::fmt;
+
+use crate::{SemanticVersion, TagFormat};
+
+const VERSION: &str = "{version}";
+
+impl TagFormat {
+    /// Tag format from a template with a `{version}` placeholder, such as
+    /// `v{version}` or `chog@{version}`.
+    ///
+    /// A template without the placeholder is used as a prefix.
+    pub fn new<T: AsRef<str>>(template: T) -> Self {
+        let template = template.as_ref();
+        let (prefix, suffix) = template.split_once(VERSION).unwrap_or((template, ""));
+        Self {
+            prefix: prefix.to_owned(),
+            suffix: suffix.to_owned(),
+        }
+    }
+
+    /// Tags made up of just the version, such as `1.2.3`.
+    pub fn bare() -> Self {
+        Self::new("")
+    }
+
+    /// Tags for a package in a workspace, such as `chog-cli-v1.2.3`.
+    pub fn package<T: fmt::Display>(name: T) -> Self {
+        Self::new(format!("{}-v", name))
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Tag name for `version`.
+    pub fn tag(&self, version: &impl fmt::Display) -> String {
+        format!("{}{}{}", self.prefix, version, self.suffix)
+    }
+
+    /// Version of a tag in this format.
+    ///
+    /// Returns `None` for tags of other formats, so tags of different packages
+    /// in the same repository can be told apart.
+    pub fn parse_tag<'t>(&self, tag: &'t str) -> Option<SemanticVersion<'t>> {
+        SemanticVersion::try_from(self.version_of(tag)?).ok()
+    }
+
+    /// Format of an existing `tag` of `version`, such as `v{version}` for the
+    /// `v1.2.3` tag of `1.2.3`.
+    ///
+    /// Returns `None` when the tag is not a tag of the version, such as `v11.0.0`
+    /// for `1.0.0`.
+    pub(crate) fn from_tag(tag: &str, version: &str) -> Option<Self> {
+        let start = tag.rfind(version)?;
+        let format = Self {
+            prefix: tag[..start].to_owned(),
+            suffix: tag[start + version.len()..].to_owned(),
+        };
+        let continues_version = |c: char| c.is_ascii_digit() || c == '.';
+        let separated = !format.prefix.ends_with(continues_version)
+            && !format.suffix.starts_with(continues_version);
+        match separated && format.version_of(tag) == Some(version) {
+            true => Some(format),
+            false => None,
+        }
+    }
+
+    /// The version part of a tag in this format, such as `1.2.3` of `v1.2.3`.
+    fn version_of<'t>(&self, tag: &'t str) -> Option<&'t str> {
+        let version = tag
+            .strip_prefix(self.prefix.as_str())?
+            .strip_suffix(self.suffix.as_str())?;
+        match version.starts_with(|c: char| c.is_ascii_digit()) {
+            true => Some(version),
+            false => None,
+        }
+    }
+
+    pub fn matches(&self, tag: &str) -> bool {
+        self.parse_tag(tag).is_some()
+    }
+}
+
+/// Tags with a `v` prefix, such as `v1.2.3`.
+impl Default for TagFormat {
+    fn default() -> Self {
+        Self::new("v")
+    }
+}
+
+/// Writes the template, such as `v{version}`.
+impl fmt::Display for TagFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.prefix, VERSION, self.suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version() -> SemanticVersion<'static> {
+        SemanticVersion::new(1, 2, 3)
+    }
+
+    #[test]
+    fn tag_names() {
+        let cases = [
+            ("v1.2.3", TagFormat::default()),
+            ("1.2.3", TagFormat::bare()),
+            ("chog-cli-v1.2.3", TagFormat::package("chog-cli")),
+            ("chog@1.2.3", TagFormat::new("chog@{version}")),
+            ("chog@1.2.3", TagFormat::new("chog@")),
+            (
+                "release-1.2.3-final",
+                TagFormat::new("release-{version}-final"),
+            ),
+        ];
+        for (expected, format) in cases {
+            assert_eq!(expected, format.tag(&version()), "format: `{}`", format);
+        }
+    }
+
+    #[test]
+    fn parse_tags() {
+        let cases = [
+            (TagFormat::default(), "v1.2.3"),
+            (TagFormat::bare(), "1.2.3"),
+            (TagFormat::package("chog-cli"), "chog-cli-v1.2.3"),
+            (TagFormat::new("chog@{version}"), "chog@1.2.3"),
+            (
+                TagFormat::new("release-{version}-final"),
+                "release-1.2.3-final",
+            ),
+        ];
+        for (format, tag) in cases {
+            assert_eq!(Some(version()), format.parse_tag(tag), "tag: `{}`", tag);
+        }
+    }
+
+    #[test]
+    fn other_formats_not_matched() {
+        let chog = TagFormat::package("chog");
+        assert!(chog.matches("chog-v1.2.3"));
+        assert!(!chog.matches("chog-cli-v1.2.3"));
+        assert!(!chog.matches("v1.2.3"));
+        assert!(!TagFormat::bare().matches("v1.2.3"));
+        assert!(!TagFormat::default().matches("vv1.2.3"));
+        assert!(!TagFormat::default().matches("v1.2"));
+    }
+
+    #[test]
+    fn formats_of_tags() {
+        let cases = [
+            ("v1.2.3", "1.2.3", Some(TagFormat::default())),
+            ("1.2.3", "1.2.3", Some(TagFormat::bare())),
+            (
+                "chog-cli-v1.2.3",
+                "1.2.3",
+                Some(TagFormat::package("chog-cli")),
+            ),
+            (
+                "release-1.2.3.4-final",
+                "1.2.3.4",
+                Some(TagFormat::new("release-{version}-final")),
+            ),
+            ("v11.2.3", "1.2.3", None),
+            ("v1.2.34", "1.2.3", None),
+            ("v1.2.3", "1.2", None),
+            ("main", "1.2.3", None),
+        ];
+        for (tag, version, expected) in cases {
+            let actual = TagFormat::from_tag(tag, version);
+            assert_eq!(expected, actual, "`{}` of `{}`", tag, version);
+        }
+    }
+
+    #[test]
+    fn pre_release_tags() {
+        let version = SemanticVersion::new_with_label(1, 0, 0, "rc.1");
+        let format = TagFormat::new("chog@");
+        assert_eq!("chog@1.0.0-rc.1", format.tag(&version));
+        assert_eq!(Some(version), format.parse_tag("chog@1.0.0-rc.1"));
+    }
+}
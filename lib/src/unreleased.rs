@@ -29,6 +29,7 @@ impl<'c> Unreleased {
             date: None,
             url,
             content,
+            yanked: false,
         }
     }
 }
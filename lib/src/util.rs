@@ -14,10 +14,10 @@ pub fn optcow_to_owned(cow: Option<Cow<'_, str>>) -> Option<Cow<'static, str>> {
 
 #[allow(unused)]
 pub fn trim_to_optcow<'a, T: Into<Cow<'a, str>>>(input: T) -> Option<Cow<'a, str>> {
-    return match input.into() {
+    match input.into() {
         Cow::Borrowed(input) => trim_to_optcow_borrow(input),
         Cow::Owned(input) => trim_to_optcow_owned(input),
-    };
+    }
 }
 
 pub fn trim_to_optcow_borrow(input: &str) -> Option<Cow<'_, str>> {
@@ -45,3 +45,17 @@ fn trim_owned_string(input: &mut String) {
     // trim end
     input.truncate(input.trim_end().len());
 }
+
+/// Slice of `content` from the start of `first` to the end of `last`.
+///
+/// Both `first` and `last` must be subslices of `content`.
+pub fn span<'c>(content: &'c str, first: &str, last: &str) -> &'c str {
+    &content[offset(content, first)..offset(content, last) + last.len()]
+}
+
+/// Byte offset of `part` in `content`.
+///
+/// `part` must be a subslice of `content`.
+pub fn offset(content: &str, part: &str) -> usize {
+    part.as_ptr() as usize - content.as_ptr() as usize
+}
@@ -0,0 +1,311 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    BumpError, CalVerFormat, InvalidScheme, NextVersion, NumericVersion, Release, ReleaseDate,
+    ReleaseTitle, SemanticVersion, VersionScheme,
+};
+
+impl VersionScheme {
+    /// Whether the `release` title is a version of this scheme.
+    ///
+    /// As short years look like any other version, a `YY.MM.MICRO` version
+    /// also needs a release date in its year.
+    pub fn matches(&self, release: &Release) -> bool {
+        match (self, release.title()) {
+            (Self::SemVer, ReleaseTitle::SemVer(_)) => true,
+            (Self::CalVer(format), ReleaseTitle::SemVer(version)) => {
+                format.matches(version, release.release_date())
+            }
+            (Self::Numeric, ReleaseTitle::SemVer(version)) => !version.is_pre_release(),
+            (Self::Numeric, ReleaseTitle::Numeric(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// The version after `current`, or the first version of the scheme.
+    ///
+    /// Calendar versions are made from the release `date`, so the `major`,
+    /// `minor` and `patch` keywords all give the same version.
+    pub fn bump(
+        &self,
+        current: Option<&ReleaseTitle>,
+        next: &NextVersion,
+        date: ReleaseDate,
+    ) -> Result<ReleaseTitle<'static>, BumpError> {
+        match self {
+            Self::SemVer => {
+                let version = match current.and_then(ReleaseTitle::semver) {
+                    Some(current) => current.bump(next)?,
+                    None => SemanticVersion::new(0, 0, 0).bump(next)?,
+                };
+                Ok(ReleaseTitle::SemVer(version.to_owned()))
+            }
+            Self::CalVer(format) => {
+                let current = current.and_then(ReleaseTitle::semver);
+                Ok(ReleaseTitle::SemVer(format.bump(current, next, date)?))
+            }
+            Self::Numeric => {
+                let current = match current {
+                    Some(ReleaseTitle::Numeric(version)) => version.clone(),
+                    Some(ReleaseTitle::SemVer(version)) => NumericVersion::from(version),
+                    _ => NumericVersion::new([0, 0, 0]),
+                };
+                Ok(current.bump(next)?.into())
+            }
+        }
+    }
+}
+
+impl CalVerFormat {
+    fn matches(&self, version: &SemanticVersion, date: Option<ReleaseDate>) -> bool {
+        let (year, month) = (version.major(), version.minor());
+        let valid = !version.is_pre_release() && (1..=12).contains(&month);
+        match self {
+            Self::YearMonthDay => {
+                valid && (1000..=9999).contains(&year) && release_date(version).is_some()
+            }
+            Self::ShortYearMonthMicro => {
+                valid && date.is_some_and(|date| u64::from(date.year()) == 2000 + year)
+            }
+        }
+    }
+
+    fn bump(
+        &self,
+        current: Option<&SemanticVersion>,
+        next: &NextVersion,
+        date: ReleaseDate,
+    ) -> Result<SemanticVersion<'static>, BumpError> {
+        let year = u64::from(date.year());
+        let month = u64::from(date.month());
+        let version = match next {
+            // a custom version is released on `date`, so must be a version of it
+            NextVersion::Custom(ReleaseTitle::SemVer(custom))
+                if self.matches(custom, Some(date)) =>
+            {
+                custom.to_owned()
+            }
+            NextVersion::Custom(custom) => {
+                return Err(BumpError::NotInScheme {
+                    version: custom.to_string(),
+                    scheme: VersionScheme::CalVer(*self).to_string(),
+                })
+            }
+            NextVersion::Major | NextVersion::Minor | NextVersion::Patch => match self {
+                Self::YearMonthDay => SemanticVersion::new(year, month, u64::from(date.day())),
+                Self::ShortYearMonthMicro => {
+                    let year = year.saturating_sub(2000);
+                    let micro = match current {
+                        Some(current) if (current.major(), current.minor()) == (year, month) => {
+                            current
+                                .patch()
+                                .checked_add(1)
+                                .ok_or_else(|| BumpError::Overflow(current.to_string()))?
+                        }
+                        _ => 0,
+                    };
+                    SemanticVersion::new(year, month, micro)
+                }
+            },
+            NextVersion::Auto => return Err(BumpError::Unresolved),
+            _ => {
+                let scheme = VersionScheme::CalVer(*self);
+                return Err(BumpError::NoPreReleases(scheme.to_string()));
+            }
+        };
+
+        match current {
+            Some(current) if &version <= current => Err(BumpError::NotGreater {
+                current: current.to_string(),
+                next: version.to_string(),
+            }),
+            _ => Ok(version),
+        }
+    }
+}
+
+/// The date of a `YYYY.MM.DD` version.
+fn release_date(version: &SemanticVersion) -> Option<ReleaseDate> {
+    ReleaseDate::new(
+        version.major().try_into().ok()?,
+        version.minor().try_into().ok()?,
+        version.patch().try_into().ok()?,
+    )
+    .ok()
+}
+
+impl FromStr for VersionScheme {
+    type Err = InvalidScheme;
+
+    /// Parse `semver`, `YYYY.MM.DD`, `YY.MM.MICRO` or `numeric`, ignoring
+    /// case.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let schemes = [
+            Self::SemVer,
+            Self::CalVer(CalVerFormat::YearMonthDay),
+            Self::CalVer(CalVerFormat::ShortYearMonthMicro),
+            Self::Numeric,
+        ];
+        schemes
+            .into_iter()
+            .find(|scheme| scheme.to_string().eq_ignore_ascii_case(input.trim()))
+            .ok_or_else(|| InvalidScheme::from(input))
+    }
+}
+
+impl TryFrom<&str> for VersionScheme {
+    type Error = InvalidScheme;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+impl fmt::Display for VersionScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SemVer => "semver",
+            Self::CalVer(CalVerFormat::YearMonthDay) => "YYYY.MM.DD",
+            Self::CalVer(CalVerFormat::ShortYearMonthMicro) => "YY.MM.MICRO",
+            Self::Numeric => "numeric",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YMD: VersionScheme = VersionScheme::CalVer(CalVerFormat::YearMonthDay);
+    const YY_MM_MICRO: VersionScheme = VersionScheme::CalVer(CalVerFormat::ShortYearMonthMicro);
+
+    fn date() -> ReleaseDate {
+        ReleaseDate::new(2024, 10, 3).expect("valid date")
+    }
+
+    fn check_bump(expected: &str, scheme: VersionScheme, current: Option<&str>, next: NextVersion) {
+        let current = current.map(ReleaseTitle::from);
+        let actual = scheme
+            .bump(current.as_ref(), &next, date())
+            .expect("valid bump");
+        assert_eq!(
+            expected,
+            actual.to_string(),
+            "{} from {:?}",
+            scheme,
+            current
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for scheme in [
+            VersionScheme::SemVer,
+            YMD,
+            YY_MM_MICRO,
+            VersionScheme::Numeric,
+        ] {
+            assert_eq!(Ok(scheme), scheme.to_string().parse());
+        }
+        assert_eq!(Ok(YY_MM_MICRO), VersionScheme::try_from("yy.mm.micro"));
+        assert_eq!(
+            Err(InvalidScheme::from("calver")),
+            VersionScheme::try_from("calver")
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let cases = [
+            (VersionScheme::SemVer, "1.0.0-rc.1", true),
+            (VersionScheme::SemVer, "1.2.3.4", false),
+            (YMD, "2024.10.3", true),
+            (YMD, "2024.2.30", false),
+            (YMD, "24.10.3", false),
+            (YY_MM_MICRO, "24.10.3", true),
+            (YY_MM_MICRO, "24.13.0", false),
+            (YY_MM_MICRO, "2024.10.3", false),
+            (YY_MM_MICRO, "1.2.3", false),
+            (YY_MM_MICRO, "0.1.0", false),
+            (VersionScheme::Numeric, "1.2.3.4", true),
+            (VersionScheme::Numeric, "1.2.3", true),
+            (VersionScheme::Numeric, "1.2.3-rc.1", false),
+            (VersionScheme::Numeric, "Unreleased", false),
+        ];
+        for (scheme, title, expected) in cases {
+            let release = Release::new(title, "2024-10-03");
+            assert_eq!(expected, scheme.matches(&release), "{} {}", scheme, title);
+        }
+        let undated = Release::new("24.10.3", "");
+        assert!(!YY_MM_MICRO.matches(&undated));
+    }
+
+    #[test]
+    fn bump_calver() {
+        check_bump("2024.10.3", YMD, None, NextVersion::Patch);
+        check_bump("2024.10.3", YMD, Some("2024.9.30"), NextVersion::Major);
+        check_bump("24.10.0", YY_MM_MICRO, None, NextVersion::Minor);
+        check_bump("24.10.0", YY_MM_MICRO, Some("24.9.4"), NextVersion::Patch);
+        check_bump("24.10.2", YY_MM_MICRO, Some("24.10.1"), NextVersion::Patch);
+        let custom = NextVersion::Custom(SemanticVersion::new(24, 11, 0).into());
+        check_bump("24.11.0", YY_MM_MICRO, Some("24.10.1"), custom);
+    }
+
+    #[test]
+    fn bump_calver_errors() {
+        let current = ReleaseTitle::from("2024.10.3");
+        let err = YMD.bump(Some(&current), &NextVersion::Patch, date());
+        let expected = BumpError::NotGreater {
+            current: "2024.10.3".into(),
+            next: "2024.10.3".into(),
+        };
+        assert_eq!(Err(expected), err);
+
+        let err = YY_MM_MICRO.bump(None, &NextVersion::PreRelease(None), date());
+        assert_eq!(Err(BumpError::NoPreReleases("YY.MM.MICRO".into())), err);
+
+        let cases = [
+            (YY_MM_MICRO, "1.2.3"),
+            (YY_MM_MICRO, "23.12.0"),
+            (YY_MM_MICRO, "24.13.0"),
+            (YY_MM_MICRO, "24.11.0-rc.1"),
+            (YMD, "2024.2.30"),
+            (YMD, "24.10.3"),
+            (YMD, "2024.10.3.1"),
+        ];
+        for (scheme, custom) in cases {
+            let next = NextVersion::Custom(ReleaseTitle::from(custom));
+            let expected = BumpError::NotInScheme {
+                version: custom.into(),
+                scheme: scheme.to_string(),
+            };
+            assert_eq!(
+                Err(expected),
+                scheme.bump(None, &next, date()),
+                "{}",
+                custom
+            );
+        }
+    }
+
+    #[test]
+    fn bump_numeric() {
+        let scheme = VersionScheme::Numeric;
+        check_bump("0.1.0", scheme, None, NextVersion::Minor);
+        check_bump("1.2.4", scheme, Some("1.2.3"), NextVersion::Patch);
+        check_bump("1.2.3.5", scheme, Some("1.2.3.4"), NextVersion::Patch);
+        check_bump("2.0", scheme, Some("1.9"), NextVersion::Major);
+    }
+
+    #[test]
+    fn bump_semver() {
+        let scheme = VersionScheme::SemVer;
+        check_bump("0.0.1", scheme, None, NextVersion::Patch);
+        check_bump(
+            "1.0.0-rc.2",
+            scheme,
+            Some("1.0.0-rc.1"),
+            NextVersion::PreRelease(None),
+        );
+    }
+}
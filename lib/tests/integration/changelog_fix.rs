@@ -0,0 +1,32 @@
+use chog::{lint::Rule, Changelog};
+
+const FULL: &str = include_str!("../../../test_changelogs/full.md");
+
+#[test]
+fn fix_full_unchanged() {
+    let mut changelog = Changelog::parse(FULL).expect("valid changelog");
+    assert!(!changelog.fix(&Rule::ALL));
+    assert_eq!(FULL, changelog.to_string());
+}
+
+#[test]
+fn fix_full_broken() {
+    let release_0_2_0 = "## [0.2.0] - 2022-09-20\n\n### Changed\n\n\
+        - Deprecate `Config::new`.\n\
+        - Fix over-zealous errors.\n\
+        - [**BREAKING CHANGE**] `Config` can no longer be converted from `App`.\n\n";
+    // out of order, misspelled, with an empty section and a missing link
+    let moved = release_0_2_0.replace("### Changed", "### Removed\n\n### changed");
+    let input = FULL
+        .replace(release_0_2_0, "")
+        .replace("## [0.1.0]", &format!("{}## [0.1.0]", moved))
+        .replace(
+            "[0.1.1]: https://github.com/user/repo/releases/tag/v0.1.1\n",
+            "",
+        );
+    assert_ne!(FULL, input);
+
+    let mut changelog = Changelog::parse(&input).expect("valid changelog");
+    assert!(changelog.fix(&Rule::ALL));
+    assert_eq!(FULL, changelog.to_string());
+}
@@ -0,0 +1,145 @@
+use chog::{
+    CalVerFormat, Changelog, NextVersion, PreReleaseFold, ReleaseDate, ReleaseOptions, TagFormat,
+    VersionScheme,
+};
+
+const FULL: &str = include_str!("../../../test_changelogs/full.md");
+
+fn released(next: NextVersion, options: &ReleaseOptions) -> String {
+    let mut changelog = Changelog::parse(FULL).expect("valid changelog");
+    changelog
+        .release_with(
+            next,
+            ReleaseDate::new(2022, 10, 20).expect("valid date"),
+            options,
+        )
+        .expect("valid release");
+    changelog.to_string()
+}
+
+#[test]
+fn release_full() {
+    let actual = released(NextVersion::Minor, &ReleaseOptions::new());
+    let expected = FULL
+        .replace(
+            "## [Unreleased]\n",
+            "## [Unreleased]\n\n## [1.1.0] - 2022-10-20\n",
+        )
+        .replace(
+            "[Unreleased]: https://github.com/user/repo/compare/v1.0.0...HEAD\n",
+            "[Unreleased]: https://github.com/user/repo/compare/v1.1.0...HEAD\n\
+            [1.1.0]: https://github.com/user/repo/releases/tag/v1.1.0\n",
+        );
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn release_full_keep_links() {
+    let actual = released(NextVersion::Patch, &ReleaseOptions::new().keep_links(true));
+    let expected = FULL.replace(
+        "## [Unreleased]\n",
+        "## [Unreleased]\n\n## [1.0.1] - 2022-10-20\n",
+    );
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn release_full_fold_pre_releases() {
+    // the changelog before 1.0.0 was released
+    let before = FULL
+        .replace(
+            "## [1.0.0] - 2022-10-03\n\n- Stabilized API\n\n### Documentation\n\n- More examples.\n\n",
+            "",
+        )
+        .replace("v1.0.0...HEAD", "v1.0.0-beta...HEAD")
+        .replace("[1.0.0]: https://github.com/user/repo/releases/tag/v1.0.0\n", "");
+    let mut changelog = Changelog::parse(&before).expect("valid changelog");
+    let options = ReleaseOptions::new().fold_pre_releases(PreReleaseFold::Remove);
+    changelog
+        .release_with(
+            NextVersion::Release,
+            ReleaseDate::new(2022, 10, 20).expect("valid date"),
+            &options,
+        )
+        .expect("valid release");
+
+    let start = FULL.find("## [Unreleased]").expect("unreleased");
+    let end = FULL.find("## [0.2.0]").expect("0.2.0");
+    let expected = format!(
+        "{}{}{}",
+        &FULL[..start],
+        "## [Unreleased]\n\n\
+        ## [1.0.0] - 2022-10-20\n\n\
+        ### Added\n\n\
+        - `Config` pretty printing.\n\
+        - Builder interface to make creating Config easier.\n\n\
+        ### Changed\n\n\
+        - Fix extra newlines appearing in debug output.\n\
+        - [**BREAKING CHANGE**] Remove deprecated `Config::new` method.\n\n\
+        ### Documentation\n\n\
+        - Fix typos in readme.\n\
+        - Add example of `Config` pretty printing.\n\n",
+        &FULL[end..],
+    )
+    .replace(
+        "[1.0.0]: https://github.com/user/repo/releases/tag/v1.0.0\n\
+        [1.0.0-beta]: https://github.com/user/repo/releases/tag/v1.0.0-beta\n",
+        "[1.0.0]: https://github.com/user/repo/compare/v0.2.0...v1.0.0\n",
+    );
+    assert_eq!(expected, changelog.to_string());
+}
+
+#[test]
+fn release_calver() {
+    let input = "# Changelog\n\n\
+        ## [Unreleased]\n\n### Fixed\n\n- Bug.\n\n\
+        ## [24.10.0] - 2024-10-01\n\n### Added\n\n- Feature.\n\n\
+        [Unreleased]: https://github.com/user/repo/compare/v24.10.0...HEAD\n\
+        [24.10.0]: https://github.com/user/repo/releases/tag/v24.10.0\n";
+    let mut changelog = Changelog::parse(input).expect("valid changelog");
+    let scheme = VersionScheme::CalVer(CalVerFormat::ShortYearMonthMicro);
+    let release = changelog
+        .release_with(
+            NextVersion::Patch,
+            ReleaseDate::new(2024, 10, 20).expect("valid date"),
+            &ReleaseOptions::new().scheme(scheme),
+        )
+        .expect("valid release");
+    assert_eq!("24.10.1", release.title_string());
+    assert_eq!(
+        Some("https://github.com/user/repo/releases/tag/v24.10.1"),
+        release.url()
+    );
+}
+
+#[test]
+fn release_numeric() {
+    let input =
+        "## [Unreleased]\n\n- Change.\n\n## [1.2.3.4] - 2024-10-01\n\n## [1.2.3] - 2024-09-01\n";
+    let mut changelog = Changelog::parse(input).expect("valid changelog");
+    let release = changelog
+        .release_with(
+            NextVersion::Minor,
+            ReleaseDate::new(2024, 10, 20).expect("valid date"),
+            &ReleaseOptions::new().scheme(VersionScheme::Numeric),
+        )
+        .expect("valid release");
+    assert_eq!("1.3.0.0", release.title_string());
+}
+
+#[test]
+fn release_full_tag_format() {
+    let options = ReleaseOptions::new().tag_format(TagFormat::new("chog@{version}"));
+    let actual = released(NextVersion::Minor, &options);
+    let expected = FULL
+        .replace(
+            "## [Unreleased]\n",
+            "## [Unreleased]\n\n## [1.1.0] - 2022-10-20\n",
+        )
+        .replace(
+            "[Unreleased]: https://github.com/user/repo/compare/v1.0.0...HEAD\n",
+            "[Unreleased]: https://github.com/user/repo/compare/chog@1.1.0...HEAD\n\
+            [1.1.0]: https://github.com/user/repo/releases/tag/chog@1.1.0\n",
+        );
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,47 @@
+use chog::{
+    lint::{self, Config, Rule, Severity},
+    ReleaseDate,
+};
+
+const FULL: &str = include_str!("../../../test_changelogs/full.md");
+
+fn config() -> Config {
+    Config::new().today(ReleaseDate::new(2022, 10, 20).expect("valid date"))
+}
+
+#[test]
+fn lint_full() {
+    let diagnostics = lint::check(FULL, &config()).expect("valid changelog");
+    let actual: Vec<_> = diagnostics.iter().map(ToString::to_string).collect();
+    let expected = vec![
+        "line 14: warning[section-heading]: non-standard section heading `Documentation`",
+        "line 23: warning[section-heading]: non-standard section heading `Documentation`",
+    ];
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn lint_full_as_errors() {
+    let config = config().severity(Rule::SectionHeading, Severity::Error);
+    let diagnostics = lint::check(FULL, &config).expect("valid changelog");
+    assert!(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.severity() == Severity::Error));
+
+    let config = config.allow(Rule::SectionHeading);
+    let diagnostics = lint::check(FULL, &config).expect("valid changelog");
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn lint_full_out_of_order() {
+    let input = FULL.replace("## [0.1.1] - 2022-09-10", "## [0.3.0] - 2022-09-10");
+    let diagnostics =
+        lint::check(&input, &config().allow(Rule::SectionHeading)).expect("valid changelog");
+    let codes: Vec<_> = diagnostics.iter().map(lint::Diagnostic::code).collect();
+    // the link definition is still for `0.1.1`
+    assert_eq!(
+        vec![Rule::ReleaseOrder, Rule::MissingLink, Rule::DanglingLink],
+        codes
+    );
+}
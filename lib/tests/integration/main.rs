@@ -1,2 +1,7 @@
 mod builder;
+mod changelog_fix;
+mod changelog_release;
+mod lint;
+mod parse;
 mod release;
+mod render;
@@ -0,0 +1,215 @@
+use chog::{BreakingMarkers, ChangeKind, Changelog, Entry, LinkStyle, ParseError};
+
+const FULL: &str = include_str!("../../../test_changelogs/full.md");
+const FULL_WEIRD_LINK: &str = include_str!("../../../test_changelogs/full_weird_link.md");
+const EMPTY_UNRELEASED: &str = include_str!("../../../test_changelogs/empty_unreleased.md");
+const JUST_UNRELEASED: &str = include_str!("../../../test_changelogs/just_unreleased.md");
+const NO_RELEASE: &str = include_str!("../../../test_changelogs/no_release.md");
+
+const HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).";
+
+const UNRELEASED_CONTENT: &str = "### Added
+
+- `Config` pretty printing.
+
+### Documentation
+
+- Fix typos in readme.
+- Add example of `Config` pretty printing.";
+
+fn parse(input: &str) -> Changelog<'_> {
+    Changelog::parse(input).expect("valid changelog")
+}
+
+fn check_releases(changelog: &Changelog, expected: &[(&str, &str, &str)]) {
+    let actual: Vec<_> = changelog
+        .releases()
+        .iter()
+        .map(|rel| (rel.title_string(), rel.date(), rel.url()))
+        .collect();
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|&(title, date, url)| (title.to_owned(), Some(date), Some(url)))
+        .collect();
+    assert_eq!(expected, actual);
+}
+
+const RELEASES: [(&str, &str, &str); 5] = [
+    (
+        "1.0.0",
+        "2022-10-03",
+        "https://github.com/user/repo/releases/tag/v1.0.0",
+    ),
+    (
+        "1.0.0-beta",
+        "2022-09-28",
+        "https://github.com/user/repo/releases/tag/v1.0.0-beta",
+    ),
+    (
+        "0.2.0",
+        "2022-09-20",
+        "https://github.com/user/repo/releases/tag/v0.2.0",
+    ),
+    (
+        "0.1.1",
+        "2022-09-10",
+        "https://github.com/user/repo/releases/tag/v0.1.1",
+    ),
+    (
+        "0.1.0",
+        "2022-09-01",
+        "https://github.com/user/repo/releases/tag/v0.1.0",
+    ),
+];
+
+#[test]
+fn full() {
+    let changelog = parse(FULL);
+    assert_eq!(Some(HEADER), changelog.header());
+
+    let unreleased = changelog.unreleased();
+    assert_eq!("Unreleased", unreleased.title_string());
+    assert_eq!(Some(UNRELEASED_CONTENT), unreleased.content());
+    assert_eq!(
+        Some("https://github.com/user/repo/compare/v1.0.0...HEAD"),
+        unreleased.url()
+    );
+
+    check_releases(&changelog, &RELEASES);
+    assert_eq!(
+        Some("### Added\n\n- `App` and `Config` APIs"),
+        changelog.releases()[4].content()
+    );
+    assert_eq!(
+        Some("- Stabilized API\n\n### Documentation\n\n- More examples."),
+        changelog.releases()[0].content()
+    );
+    assert_eq!(0, changelog.misc_links().count());
+}
+
+#[test]
+fn full_weird_link_same_as_full() {
+    let mut weird = parse(FULL_WEIRD_LINK);
+    assert_eq!(LinkStyle::Inline, weird.link_style());
+    weird.set_link_style(LinkStyle::Reference);
+    assert_eq!(parse(FULL), weird);
+}
+
+#[test]
+fn empty_unreleased() {
+    let changelog = parse(EMPTY_UNRELEASED);
+    assert_eq!(Some(HEADER), changelog.header());
+    assert_eq!(None, changelog.unreleased().content());
+    assert_eq!(
+        Some("https://github.com/user/repo/compare/v1.0.0...HEAD"),
+        changelog.unreleased().url()
+    );
+    check_releases(&changelog, &RELEASES);
+}
+
+#[test]
+fn just_unreleased() {
+    let changelog = parse(JUST_UNRELEASED);
+    assert_eq!(None, changelog.header());
+    assert_eq!(Some(UNRELEASED_CONTENT), changelog.unreleased().content());
+    assert!(changelog.releases().is_empty());
+}
+
+#[test]
+fn no_release() {
+    let changelog = parse(NO_RELEASE);
+    assert_eq!(Some(HEADER), changelog.header());
+    assert_eq!(Some(UNRELEASED_CONTENT), changelog.unreleased().content());
+    assert_eq!(None, changelog.unreleased().url());
+    assert!(changelog.releases().is_empty());
+}
+
+#[test]
+fn text_is_borrowed() {
+    let changelog = parse(FULL);
+    let content = changelog.unreleased().content().expect("content");
+    let range = FULL.as_bytes().as_ptr_range();
+    assert!(range.contains(&content.as_ptr()));
+}
+
+#[test]
+fn misc_links_kept() {
+    let input = "# Changelog\n\n## [Unreleased]\n\n- See [docs].\n\n\
+        [Unreleased]: https://a.b/compare/v1.0.0...HEAD\n\
+        [docs]: https://docs.rs/chog\n";
+    let changelog = parse(input);
+    assert_eq!(Some("- See [docs]."), changelog.unreleased().content());
+    assert_eq!(
+        vec!["[docs]: https://docs.rs/chog"],
+        changelog.misc_links().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn empty_input() {
+    let changelog = parse("");
+    assert_eq!(Changelog::new(), changelog);
+}
+
+#[test]
+fn errors() {
+    let cases = [
+        (
+            ParseError::DuplicateUnreleased(3),
+            "## [Unreleased]\n\n## [Unreleased]\n",
+        ),
+        (
+            ParseError::MalformedHeading(2),
+            "\n## [1.0.0 - 2022-10-03\n",
+        ),
+        (ParseError::MissingTitle(1), "## [] - 2022-10-03\n"),
+    ];
+    for (expected, input) in cases {
+        let actual = Changelog::parse(input).expect_err("invalid changelog");
+        assert_eq!(expected, actual, "input: {:?}", input);
+    }
+}
+
+#[test]
+fn breaking_changes() {
+    let changelog = parse(FULL);
+    let breaking: Vec<_> = changelog
+        .releases()
+        .iter()
+        .map(|rel| (rel.title_string(), rel.has_breaking_changes()))
+        .filter(|(_, breaking)| *breaking)
+        .map(|(title, _)| title)
+        .collect();
+    assert_eq!(vec!["1.0.0-beta", "0.2.0"], breaking);
+    assert!(!changelog.unreleased().has_breaking_changes());
+
+    let beta = &changelog.releases()[1];
+    let changed = beta
+        .sections()
+        .find(|section| section.kind() == &ChangeKind::Changed)
+        .expect("changed section");
+    let flags: Vec<_> = changed.entries().iter().map(Entry::is_breaking).collect();
+    assert_eq!(vec![false, true], flags);
+}
+
+#[test]
+fn breaking_section_heading() {
+    let changelog = parse(include_str!("../../../CHANGELOG.md"));
+    let unreleased = changelog.unreleased();
+    let project = unreleased.sections().next().expect("first section");
+    assert!(project.is_breaking());
+    assert_eq!(
+        &ChangeKind::Custom("Project changes".into()),
+        project.kind()
+    );
+    assert!(project.entries().iter().all(|entry| !entry.is_breaking()));
+    assert!(unreleased.has_breaking_changes());
+
+    let markers = BreakingMarkers::with(["BREAKING:"]);
+    assert!(!unreleased.has_breaking_changes_with(&markers));
+}
@@ -1,4 +1,4 @@
-use chog::Unreleased;
+use chog::{ChangeKind, Release, ReleaseDate, Unreleased};
 
 #[test]
 fn prop_access() {
@@ -11,6 +11,21 @@ fn prop_access() {
     assert_eq!(None, rel.date());
 }
 
+#[test]
+fn lenient_dates() {
+    let valid = Release::new("1.0.0", "2022-10-03");
+    assert_eq!(ReleaseDate::new(2022, 10, 3).ok(), valid.release_date());
+
+    let mut invalid = Release::new("1.0.0", "Oct 3");
+    assert_eq!(Some("Oct 3"), invalid.date());
+    assert_eq!(None, invalid.release_date());
+
+    let date = ReleaseDate::new(2022, 10, 20).expect("valid date");
+    invalid.set_date(date);
+    assert_eq!(Some("2022-10-20"), invalid.date());
+    assert_eq!(Some(date), invalid.release_date());
+}
+
 #[test]
 fn edit_empty_content() {
     let mut rel = Unreleased::with_url("testurl");
@@ -56,3 +71,57 @@ fn set_content() {
     rel.set_content("testcontent 2");
     assert_eq!(Some("testcontent 2"), rel.content());
 }
+
+#[test]
+fn sections_from_content() {
+    let rel = Unreleased::with_content("### Added\n\n- New.\n\n### Fixed\n\n- Bug.");
+    let sections: Vec<_> = rel
+        .sections()
+        .map(|section| (section.kind().to_string(), section.entries().len()))
+        .collect();
+    assert_eq!(
+        vec![("Added".to_owned(), 1), ("Fixed".to_owned(), 1)],
+        sections
+    );
+}
+
+#[test]
+fn add_entry_updates_content() {
+    let mut rel = Unreleased::with_content("### Fixed\n\n- Bug.");
+    rel.add_entry(ChangeKind::Fixed, "Another bug.");
+    rel.add_entry(ChangeKind::Added, String::from("Feature."));
+    assert_eq!(
+        Some("### Added\n\n- Feature.\n\n### Fixed\n\n- Bug.\n- Another bug."),
+        rel.content()
+    );
+}
+
+#[test]
+fn add_entry_to_empty_content() {
+    let mut rel = Unreleased::empty();
+    rel.add_entry(ChangeKind::Security, "Patch.");
+    assert_eq!(Some("### Security\n\n- Patch."), rel.content());
+}
+
+#[test]
+fn remove_entry_updates_content() {
+    let mut rel = Unreleased::with_content("### Fixed\n\n- Bug.\n- Another bug.");
+    assert_eq!(
+        Some("Bug.".to_owned()),
+        rel.remove_entry(&ChangeKind::Fixed, 0)
+    );
+    assert_eq!(None, rel.remove_entry(&ChangeKind::Fixed, 1));
+    assert_eq!(None, rel.remove_entry(&ChangeKind::Added, 0));
+    assert_eq!(Some("### Fixed\n\n- Another bug."), rel.content());
+}
+
+#[test]
+fn add_group_entry_updates_content() {
+    let mut rel = Unreleased::with_content("### Added\n\n#### Cli\n\n- Help.");
+    rel.add_group_entry(ChangeKind::Added, "Lib", "Parsing.");
+    rel.add_group_entry(ChangeKind::Added, "Cli", "Info.");
+    assert_eq!(
+        Some("### Added\n\n#### Cli\n\n- Help.\n- Info.\n\n#### Lib\n\n- Parsing."),
+        rel.content()
+    );
+}
@@ -0,0 +1,185 @@
+use chog::{Changelog, LinkStyle, NextVersion, ReleaseDate, ReleaseOptions, ReleaseTitle};
+
+const FULL: &str = include_str!("../../../test_changelogs/full.md");
+const FULL_WEIRD_LINK: &str = include_str!("../../../test_changelogs/full_weird_link.md");
+const LINK_LAYOUT: &str = include_str!("../../../test_changelogs/link_layout.md");
+
+fn check_round_trip(input: &str) {
+    let changelog = Changelog::parse(input).expect("valid changelog");
+    let actual = changelog.to_string();
+    assert_eq!(
+        input, actual,
+        "\n# EXPECTED:\n{}\n# ACTUAL:\n{}",
+        input, actual
+    );
+}
+
+#[test]
+fn round_trip_full() {
+    check_round_trip(FULL);
+}
+
+#[test]
+fn round_trip_inline_links() {
+    check_round_trip(FULL_WEIRD_LINK);
+}
+
+#[test]
+fn convert_link_styles() {
+    let mut changelog = Changelog::parse(FULL).expect("valid changelog");
+    changelog.set_link_style(LinkStyle::Inline);
+    assert_eq!(FULL_WEIRD_LINK, changelog.to_string());
+
+    let mut changelog = Changelog::parse(FULL_WEIRD_LINK).expect("valid changelog");
+    changelog.set_link_style(LinkStyle::Reference);
+    assert_eq!(FULL, changelog.to_string());
+}
+
+#[test]
+fn round_trip_empty_unreleased() {
+    check_round_trip(include_str!("../../../test_changelogs/empty_unreleased.md"));
+}
+
+#[test]
+fn round_trip_no_release() {
+    check_round_trip(include_str!("../../../test_changelogs/no_release.md"));
+}
+
+#[test]
+fn round_trip_project_changelog() {
+    check_round_trip(include_str!("../../../CHANGELOG.md"));
+}
+
+#[test]
+fn round_trip_misc_links() {
+    check_round_trip(
+        "# Changelog\n\n## [Unreleased]\n\n- See [docs].\n\n\
+        [Unreleased]: https://a.b/compare/v1.0.0...HEAD\n\
+        [docs]: https://docs.rs/chog\n",
+    );
+}
+
+#[test]
+fn round_trip_link_layout() {
+    check_round_trip(LINK_LAYOUT);
+}
+
+#[test]
+fn round_trip_reordered_links() {
+    check_round_trip(
+        "## [Unreleased]\n\n- Change.\n\n## [1.0.0] - 2022-10-03\n\n- Stable.\n\n\
+        [1.0.0]: https://a.b/releases/tag/v1.0.0\n\
+        [docs]: https://docs.rs/chog\n\
+        [Unreleased]: https://a.b/compare/v1.0.0...HEAD\n",
+    );
+}
+
+#[test]
+fn round_trip_links_between_releases() {
+    check_round_trip(
+        "# Changelog\n\n[docs]: https://docs.rs/chog\n\n\
+        ## [Unreleased]\n\n\
+        [Unreleased]: https://a.b/compare/v1.0.0...HEAD\n\n\
+        ## [1.0.0] - 2022-10-03\n\n- Stable.\n\n\
+        [1.0.0]: https://a.b/releases/tag/v1.0.0\n",
+    );
+}
+
+#[test]
+fn round_trip_link_labels_and_titles() {
+    check_round_trip(
+        "## [Unreleased]\n\n## [1.0.0] - 2022-10-03\n\n- Stable.\n\n\
+        [unreleased]: https://a.b/compare/v1.0.0...HEAD \"Unreleased changes\"\n\
+        [v1.0.0]: https://a.b/releases/tag/v1.0.0 'Release 1.0.0'\n",
+    );
+}
+
+#[test]
+fn round_trip_crlf() {
+    check_round_trip(&FULL.replace('\n', "\r\n"));
+    check_round_trip(&LINK_LAYOUT.replace('\n', "\r\n"));
+}
+
+#[test]
+fn release_keeps_link_layout() {
+    let mut changelog = Changelog::parse(LINK_LAYOUT).expect("valid changelog");
+    let date = ReleaseDate::new(2022, 10, 20).expect("valid date");
+    changelog
+        .release_with(NextVersion::Minor, date, &ReleaseOptions::new())
+        .expect("valid release");
+    let expected = LINK_LAYOUT
+        .replace(
+            "## [Unreleased]\n\n- Read [the docs].",
+            "## [Unreleased]\n\n## [1.1.0] - 2022-10-20\n\n- Read [the docs].",
+        )
+        .replace(
+            "[v1.0.0]: ",
+            "[1.1.0]: https://github.com/user/repo/compare/v1.0.0...v1.1.0\n[v1.0.0]: ",
+        )
+        .replace("v1.0.0...HEAD", "v1.1.0...HEAD");
+    assert_eq!(expected, changelog.to_string());
+}
+
+#[test]
+fn round_trip_yanked() {
+    check_round_trip(
+        "## [Unreleased]\n\n\
+        ## [0.0.5] - 2014-12-13 [YANKED]\n\n- Broken.\n\n\
+        ## [0.0.4] - 2014-12-01\n",
+    );
+}
+
+#[test]
+fn render_dash_separated_dates() {
+    let input = "## [Unreleased]\n\n## [1.0.0] – 2022-01-01\n\n## 0.1.0 — 2021-12-01\n";
+    let changelog = Changelog::parse(input).expect("valid changelog");
+    let dates: Vec<_> = changelog
+        .releases()
+        .iter()
+        .map(|r| r.release_date())
+        .collect();
+    assert_eq!(
+        vec![
+            Some("2022-01-01".parse().expect("valid date")),
+            Some("2021-12-01".parse().expect("valid date")),
+        ],
+        dates
+    );
+    assert_eq!(
+        "## [Unreleased]\n\n## [1.0.0] - 2022-01-01\n\n## [0.1.0] - 2021-12-01\n",
+        changelog.to_string()
+    );
+}
+
+#[test]
+fn yank_release() {
+    let input = include_str!("../../../test_changelogs/full.md");
+    let mut changelog = Changelog::parse(input).expect("valid changelog");
+    changelog
+        .yank(&ReleaseTitle::from("0.1.1"))
+        .expect("known version");
+    let expected = input.replace(
+        "## [0.1.1] - 2022-09-10",
+        "## [0.1.1] - 2022-09-10 [YANKED]",
+    );
+    assert_eq!(expected, changelog.to_string());
+}
+
+#[test]
+fn write_to_matches_input() {
+    let input = include_str!("../../../test_changelogs/full.md");
+    let changelog = Changelog::parse(input).expect("valid changelog");
+    let mut output = Vec::new();
+    changelog.write_to(&mut output).expect("write to vec");
+    assert_eq!(input.as_bytes(), output);
+}
+
+#[test]
+fn project_changelog_groups_survive_edit() {
+    let input = include_str!("../../../CHANGELOG.md");
+    let mut changelog = Changelog::parse(input).expect("valid changelog");
+    let before = changelog.unreleased().content().map(str::to_owned);
+    // rewriting the content through the structured view is lossless
+    changelog.unreleased_mut().edit_changes(|_| {});
+    assert_eq!(before.as_deref(), changelog.unreleased().content());
+}